@@ -0,0 +1,12 @@
+-- The internal errors raised by commands, kept under the code shown to the user so that support can look them up.
+-- The chain holds the error followed by each of its sources, each of which may span several lines.
+CREATE TABLE IF NOT EXISTS error_reports (
+    code TEXT PRIMARY KEY,
+    reported_at TIMESTAMPTZ NOT NULL,
+    command TEXT NOT NULL,
+    arguments TEXT NOT NULL,
+    guild_id BIGINT,
+    channel_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    error_chain TEXT[] NOT NULL
+);
@@ -0,0 +1,26 @@
+-- The infractions of every member of every guild, which were formerly kept in a table for each member under a
+-- schema for each guild.
+CREATE TABLE IF NOT EXISTS infractions (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    infraction_id TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    infraction_type TEXT NOT NULL,
+    moderator_id BIGINT,
+    created_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    revoked_by BIGINT,
+    revoked_at TIMESTAMPTZ,
+    revocation_reason TEXT
+);
+
+CREATE INDEX IF NOT EXISTS infractions_guild_id_user_id_index ON infractions (guild_id, user_id);
+CREATE INDEX IF NOT EXISTS infractions_guild_id_infraction_id_index ON infractions (guild_id, infraction_id);
+
+-- The last case number given out in each guild.
+CREATE TABLE IF NOT EXISTS infraction_case_numbers (
+    guild_id BIGINT PRIMARY KEY,
+    last_case_number BIGINT NOT NULL
+);
@@ -0,0 +1,22 @@
+-- Imports the infractions from the former tables of each member, `inf_{guild ID}.user_{user ID}`, which only kept the
+-- ID, reason and type of each infraction; the guild and user IDs are read from the names of the schema and table. The
+-- former tables are left in place.
+DO $$
+DECLARE
+    legacy_table RECORD;
+BEGIN
+    FOR legacy_table IN
+        SELECT table_schema, table_name FROM information_schema.tables
+        WHERE table_schema ~ '^inf_[0-9]+$' AND table_name ~ '^user_[0-9]+$'
+    LOOP
+        EXECUTE format(
+            'INSERT INTO infractions (guild_id, user_id, infraction_id, reason, infraction_type) '
+            'SELECT %s, %s, infraction_id, reason, infraction_type FROM %I.%I',
+            substring(legacy_table.table_schema FROM 5)::BIGINT,
+            substring(legacy_table.table_name FROM 6)::BIGINT,
+            legacy_table.table_schema,
+            legacy_table.table_name
+        );
+    END LOOP;
+END
+$$;
@@ -0,0 +1,11 @@
+-- The actions on members that are due at a later time, such as the end of a temporary mute or ban. The roles are
+-- only set for the kinds of action that remove or give back a role.
+CREATE TABLE IF NOT EXISTS scheduled_actions (
+    id BIGSERIAL PRIMARY KEY,
+    guild_id BIGINT NOT NULL,
+    user_id BIGINT NOT NULL,
+    action TEXT NOT NULL,
+    role_id BIGINT,
+    role_to_restore BIGINT,
+    due_at TIMESTAMPTZ NOT NULL
+);
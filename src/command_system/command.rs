@@ -7,7 +7,9 @@ use super::{
     parser::{
         Arguments
     },
+    CommandCategory,
     CommandContext,
+    CommandScope,
     PrecommandCheckParameters
 };
 
@@ -28,6 +30,31 @@ crate trait Command {
         Vec::new()
     }
 
+    /// A short description of what the command does, shown in `help`.
+    fn description(&self) -> String;
+
+    /// The arguments following the name of the command; `<>` wraps required and `[]` optional arguments.
+    fn usage(&self) -> String {
+        String::new()
+    }
+
+    /// Example invocations of the command, without the prefix.
+    fn examples(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    fn category(&self) -> CommandCategory;
+
+    /// Where the command can be used; most commands act on a guild, so they can only be used in one by default.
+    fn scope(&self) -> CommandScope {
+        CommandScope::GuildOnly
+    }
+
+    /// The permission level required by `HasRolePermissions`, unless the registration overrides it.
+    fn minimum_permission_level(&self) -> Option<u32> {
+        None
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>>;
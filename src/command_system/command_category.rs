@@ -0,0 +1,35 @@
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    }
+};
+
+/// The category a command is listed under in `help`, matching the plugin it belongs to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+crate enum CommandCategory {
+    General,
+    Information,
+    Infractions,
+    Administrator,
+    Utilities,
+    GuildOwneronly,
+    Owneronly,
+    Whitelist
+}
+
+impl Display for CommandCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::General => write!(f, "General"),
+            Self::Information => write!(f, "Information"),
+            Self::Infractions => write!(f, "Infractions"),
+            Self::Administrator => write!(f, "Administrator"),
+            Self::Utilities => write!(f, "Utilities"),
+            Self::GuildOwneronly => write!(f, "Guild Owner Only"),
+            Self::Owneronly => write!(f, "Owner Only"),
+            Self::Whitelist => write!(f, "Whitelist")
+        }
+    }
+}
@@ -11,6 +11,8 @@ use twilight_http::{
     Client as HttpClient
 };
 
+use twilight_standby::Standby;
+
 use twilight_model::{
     channel::{
         Message
@@ -23,9 +25,14 @@ use twilight_model::{
 
 use crate::{
     command_system::{
+        events::{
+            emitter::CommandEventEmitter
+        },
         parser::{
             CommandParser
-        }
+        },
+        CommandRegistry,
+        CommandServices
     },
     system::{
     Stopwatch
@@ -48,10 +55,14 @@ crate struct CommandContextRef<'a> {
     crate http_client: HttpClient,
     crate command_parser: CommandParser<'a>,
     crate cluster: Cluster,
+    crate command_registry: CommandRegistry,
+    crate standby: Standby,
     crate message: Message,
     crate author: User,
     crate member: Option<PartialMember>,
-    crate stopwatch: Stopwatch
+    crate stopwatch: Stopwatch,
+    crate emitter: CommandEventEmitter,
+    crate services: CommandServices
 }
 
 impl<'a> CommandContextRef<'a> {
@@ -59,8 +70,12 @@ impl<'a> CommandContextRef<'a> {
         http_client: HttpClient, 
         command_parser: CommandParser<'a>,
         cluster: Cluster,
+        command_registry: CommandRegistry,
+        standby: Standby,
         message: Message,
-        stopwatch: Stopwatch
+        stopwatch: Stopwatch,
+        emitter: CommandEventEmitter,
+        services: CommandServices
     ) -> Self {
         let author = message.clone().author;
         let member = message.clone().member;
@@ -69,10 +84,14 @@ impl<'a> CommandContextRef<'a> {
             http_client,
             command_parser,
             cluster,
+            command_registry,
+            standby,
             message,
             author,
             member,
-            stopwatch
+            stopwatch,
+            emitter,
+            services
         }
     }
 }
@@ -0,0 +1,127 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    time::Duration
+};
+
+use twilight_cache_inmemory::InMemoryCache;
+
+use crate::command_system::{
+    parser::{
+        Arguments
+    },
+    precommand_checks::{
+        Cooldown,
+        CooldownBucket,
+        PrecommandCheck
+    },
+    Command,
+    CommandCategory,
+    CommandContext,
+    CommandScope,
+    PrecommandCheckParameters
+};
+
+use crate::system::SystemResult;
+
+/// The type-erased signature of `Command::execute_command`.
+crate type CommandFunction = for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>,
+                                                         Arguments<'asynchronous_trait>, InMemoryCache)
+    -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>>;
+
+/// The type-erased signature of `PrecommandCheck::execute_check`.
+crate type PrecommandCheckFunction = for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>,
+                                                                 PrecommandCheckParameters)
+    -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>>;
+
+/// The precommand checks to run before a command is executed, all of which must pass.
+#[derive(Clone, Default)]
+crate struct CommandChecks {
+    crate checks: Vec<PrecommandCheckFunction>,
+    crate visibility_checks: Vec<PrecommandCheckFunction>,
+    crate minimum_permission_level: Option<u32>,
+    crate cooldown: Option<Cooldown>
+}
+
+impl CommandChecks {
+    crate fn new() -> Self {
+        Self::default()
+    }
+
+    crate fn check<C>(mut self, _check: C) -> Self
+    where C: PrecommandCheck {
+        self.checks.push(C::execute_check);
+
+        if C::HIDES_FROM_HELP {
+            self.visibility_checks.push(C::execute_check);
+        }
+
+        self
+    }
+
+    crate fn minimum_permission_level(mut self, permission_level: u32) -> Self {
+        self.minimum_permission_level.replace(permission_level);
+
+        self
+    }
+
+    /// Limits the command to `burst` invocations per bucket within `window`, checked after the preceding checks.
+    crate fn cooldown(mut self, bucket: CooldownBucket, window: Duration, burst: u32) -> Self {
+        self.cooldown.replace(Cooldown::new(bucket, window, burst));
+        self.checks.push(Cooldown::execute_check);
+
+        self
+    }
+}
+
+/// A registered command, with its checks and the metadata shown in `help`.
+#[derive(Clone)]
+crate struct CommandHandler {
+    crate fully_qualified_name: String,
+    /// The names the restrictions of a guild can name the command by: its fully qualified name, its name and its
+    /// aliases.
+    crate names: Vec<String>,
+    crate description: String,
+    crate usage: String,
+    crate examples: Vec<String>,
+    crate category: CommandCategory,
+    crate scope: CommandScope,
+    crate execute: CommandFunction,
+    crate checks: CommandChecks
+}
+
+impl CommandHandler {
+    crate fn new<T>(command: &T, mut checks: CommandChecks) -> Self
+    where T: Command {
+        if checks.minimum_permission_level.is_none() {
+            checks.minimum_permission_level = command.minimum_permission_level();
+        }
+
+        // Guilds may configure a cooldown for any command, so those without a default one still track invocations.
+        if checks.cooldown.is_none() {
+            checks.cooldown.replace(Cooldown::unlimited());
+            checks.checks.push(Cooldown::execute_check);
+        }
+
+        let mut names = vec![command.fully_qualified_name()];
+
+        // Commands that do not override `name` are only known by their fully qualified name and aliases.
+        if command.name() != "default" {
+            names.push(command.name());
+        }
+
+        names.extend(command.aliases());
+
+        Self {
+            fully_qualified_name: command.fully_qualified_name(),
+            names,
+            description: command.description(),
+            usage: command.usage(),
+            examples: command.examples(),
+            category: command.category(),
+            scope: command.scope(),
+            execute: T::execute_command,
+            checks
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::Instant
+};
+
+use twilight_cache_inmemory::InMemoryCache;
+
+use crate::command_system::{
+    command_restrictions::{
+        check_restrictions,
+        CommandRestrictionError
+    },
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    },
+    hooks::{
+        reply_to_error,
+        CommandHook,
+        CommandHooks,
+        CommandResult
+    },
+    parser::Command,
+    CommandContext,
+    CommandHandler,
+    PrecommandCheckParameters,
+    PrecommandCheckParametersBuilder
+};
+
+use crate::logging::logger::Logger;
+
+use crate::system::{
+    model::{
+        payload::{
+            CommandExecuted,
+            CommandFailed
+        }
+    },
+    SystemResult
+};
+
+/// Represents the registered commands, keyed by the names the parser matches them by.
+#[derive(Clone, Default)]
+crate struct CommandRegistry {
+    commands: HashMap<String, CommandHandler>,
+    aliases: HashMap<String, String>,
+    hooks: CommandHooks
+}
+
+impl CommandRegistry {
+    crate fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a handler under the name the parser matches it by, along with its aliases.
+    crate fn register(&mut self, name: String, handler: CommandHandler, aliases: Vec<String>) {
+        for alias in aliases {
+            self.aliases.insert(alias, name.clone());
+        }
+
+        self.commands.insert(name, handler);
+    }
+
+    /// Registers a hook to run around every command, after the hooks registered before it.
+    crate fn add_hook<H>(&mut self)
+    where H: CommandHook {
+        self.hooks.add::<H>();
+    }
+
+    /// Finds the handler for the name of a parsed command, which may be an alias.
+    crate fn resolve(&self, name: &str) -> Option<&CommandHandler> {
+        let name = self.aliases.get(name).map(String::as_str).unwrap_or(name);
+
+        self.commands.get(name)
+    }
+
+    /// Finds the aliases a command is registered with.
+    crate fn aliases_of(&self, name: &str) -> Vec<String> {
+        let mut aliases = self.aliases.iter()
+            .filter(|(_, command)| *command == name)
+            .map(|(alias, _)| alias.clone())
+            .collect::<Vec<_>>();
+
+        aliases.sort();
+        aliases
+    }
+
+    /// Whether a command can be used where a message was sent, and its author passes the checks that hide the command
+    /// from `help`.
+    crate async fn can_run(&self, handler: &CommandHandler, context: CommandContext<'_>, cache: InMemoryCache) -> bool {
+        Self::passes_visibility_checks(handler, context, cache, &mut HashMap::new()).await
+    }
+
+    /// Finds the commands that can be used where a message was sent, and whose checks that hide them from `help` its
+    /// author passes.
+    crate async fn visible_commands(&self, context: CommandContext<'_>, cache: InMemoryCache) -> Vec<&CommandHandler> {
+        // Most commands share a check and a permission level, so each combination only runs once.
+        let mut outcomes = HashMap::new();
+        let mut visible = Vec::new();
+
+        for handler in self.commands.values() {
+            if Self::passes_visibility_checks(handler, context.clone(), cache.clone(), &mut outcomes).await {
+                visible.push(handler);
+            }
+        }
+
+        visible
+    }
+
+    async fn passes_visibility_checks(handler: &CommandHandler, context: CommandContext<'_>, cache: InMemoryCache,
+                                      outcomes: &mut HashMap<(usize, Option<u32>), bool>) -> bool {
+        if handler.scope.check(context.message.guild_id.is_some()).is_err() {
+            return false;
+        }
+
+        let params = Self::check_parameters(handler, &context, cache);
+
+        for check in &handler.checks.visibility_checks {
+            let key = (*check as usize, handler.checks.minimum_permission_level);
+
+            let passed = match outcomes.get(&key) {
+                Some(passed) => *passed,
+                None => {
+                    let passed = check(context.clone(), params.clone()).await.is_ok();
+                    outcomes.insert(key, passed);
+
+                    passed
+                }
+            };
+
+            if !passed {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn check_parameters(handler: &CommandHandler, context: &CommandContext<'_>, cache: InMemoryCache)
+        -> PrecommandCheckParameters {
+        let mut builder = PrecommandCheckParametersBuilder::new()
+            .user_id(context.author.id)
+            .in_memory_cache(cache)
+            .command_name(handler.fully_qualified_name.clone());
+
+        if let Some(guild_id) = context.message.guild_id {
+            builder = builder.guild_id(guild_id);
+        }
+
+        if let Some(minimum_permission_level) = handler.checks.minimum_permission_level {
+            builder = builder.minimum_permission_level(minimum_permission_level);
+        }
+
+        if let Some(cooldown) = handler.checks.cooldown.clone() {
+            builder = builder.cooldown(cooldown);
+        }
+
+        builder.build()
+    }
+
+    /// Rejects a parsed command used outside of its scope, enforces the restrictions of the guild, runs the precommand
+    /// checks of the command and then executes it between the registered hooks, emitting the result.
+    ///
+    /// Returns whether the command was executed successfully.
+    crate async fn dispatch(&self, command: Command<'_>, context: CommandContext<'static>, cache: InMemoryCache,
+                            emitter: CommandEventEmitter) -> SystemResult<bool> {
+        let handler = match self.resolve(command.name) {
+            Some(handler) => handler,
+            None => {
+                Logger::log_error(
+                    format!("Command '{}' failed due to an error: 'command not found'.", context.message.content));
+
+                return Ok(false);
+            }
+        };
+
+        if let Err(error) = handler.scope.check(context.message.guild_id.is_some()) {
+            context.http_client.clone().create_message(context.message.channel_id)
+                .content(format!("<:red_x:705623424675872859> {}", error))?
+                .allowed_mentions()
+                .replied_user(false)
+                .build()
+                .reply(context.message.id)
+                .await?;
+
+            emitter.event(SystemEvent::CommandFailed(box CommandFailed {
+                command: handler.fully_qualified_name.clone(),
+                error: format!("{}", error)
+            })).await;
+
+            return Ok(false);
+        }
+
+        let restriction = check_restrictions(&context, &handler.names).await;
+
+        if let Err(error) = restriction {
+            // A disabled command is ignored, as if it was not a command in the guild.
+            if !matches!(error, CommandRestrictionError::Disabled) {
+                context.http_client.clone().create_message(context.message.channel_id)
+                    .content(format!("<:red_x:705623424675872859> {}", error))?
+                    .allowed_mentions()
+                    .replied_user(false)
+                    .build()
+                    .reply(context.message.id)
+                    .await?;
+            }
+
+            emitter.event(SystemEvent::CommandFailed(box CommandFailed {
+                command: handler.fully_qualified_name.clone(),
+                error: format!("{}", error)
+            })).await;
+
+            return Ok(false);
+        }
+
+        let arguments = command.arguments.as_str().to_string();
+        let params = Self::check_parameters(handler, &context, cache.clone());
+
+        for check in &handler.checks.checks {
+            if let Err(error) = check(context.clone(), params.clone()).await {
+                reply_to_error(context.http_client.clone(), &*context.services.error_reports,
+                               handler.fully_qualified_name.clone(), arguments, &context.message, &*error).await?;
+
+                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
+                    command: handler.fully_qualified_name.clone(),
+                    error: format!("{}", error)
+                })).await;
+
+                return Ok(false);
+            }
+        }
+
+        let started = Instant::now();
+        let mut result = Ok(());
+
+        for hook in &self.hooks.before {
+            result = hook(context.clone(), handler.fully_qualified_name.clone()).await;
+
+            if result.is_err() {
+                break;
+            }
+        }
+
+        if result.is_ok() {
+            result = (handler.execute)(context.clone(), command.arguments, cache).await;
+        }
+
+        let result: CommandResult = result.map_err(Arc::from);
+        let elapsed = started.elapsed();
+
+        for hook in &self.hooks.after {
+            let outcome = hook(context.clone(), handler.fully_qualified_name.clone(), arguments.clone(), result.clone(),
+                               elapsed).await;
+
+            if let Err(error) = outcome {
+                Logger::log_error(
+                    format!("A hook failed after command '{}' due to an error: '{}'.", handler.fully_qualified_name, error));
+            }
+        }
+
+        match result {
+            Ok(()) => {
+                let guild_name = match context.message.guild_id {
+                    Some(guild_id) => match context.http_client.guild(guild_id).await {
+                        Ok(Some(guild)) => guild.name,
+                        _ => String::new()
+                    },
+                    None => String::new()
+                };
+
+                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
+                    command: handler.fully_qualified_name.clone(),
+                    guild_name,
+                    context: context.clone()
+                })).await;
+
+                Ok(true)
+            },
+            Err(error) => {
+                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
+                    command: handler.fully_qualified_name.clone(),
+                    error: format!("{}", error)
+                })).await;
+
+                Ok(false)
+            }
+        }
+    }
+}
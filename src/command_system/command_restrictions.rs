@@ -0,0 +1,104 @@
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    }
+};
+
+use crate::command_system::CommandContext;
+
+use crate::xml_deserialization::{
+    plugin_management::CommandRestriction
+};
+
+/// Checks whether a command may be used for the message of a context, according to the restrictions of the command
+/// and its groups in the configuration of the guild, which are enforced before the precommand checks; a restriction
+/// may name the command by any of `names`. Commands in direct messages, and in guilds whose configuration cannot be
+/// read, are not restricted.
+crate async fn check_restrictions(context: &CommandContext<'_>, names: &[String])
+    -> Result<(), CommandRestrictionError> {
+    let message = &context.message;
+    let guild_id = match message.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(())
+    };
+
+    let config = match context.services.guild_configs.config(guild_id).await {
+        Ok(config) => config,
+        Err(_) => return Ok(())
+    };
+
+    let restrictions = config.plugins.command_restrictions.restrictions.iter()
+        .filter(|restriction| names.iter().any(|name| applies_to(restriction, name)))
+        .collect::<Vec<_>>();
+
+    let restricts_roles = restrictions.iter()
+        .any(|restriction| !restriction.allowed_roles.role_ids.is_empty() || !restriction.denied_roles.role_ids.is_empty());
+
+    let roles = match &message.member {
+        Some(member) => member.roles.clone(),
+        // Messages fetched again to re-run edited commands have no member, so the roles are looked up instead.
+        None if restricts_roles => context.http_client.clone().guild_member(guild_id, message.author.id).await
+            .ok()
+            .flatten()
+            .map(|member| member.roles)
+            .unwrap_or_default(),
+        None => Vec::new()
+    };
+
+    for restriction in restrictions {
+        if !restriction.enabled {
+            return Err(CommandRestrictionError::Disabled);
+        }
+
+        let channel_id = message.channel_id.0;
+        let allowed_channels = &restriction.allowed_channels.channel_ids;
+
+        if restriction.denied_channels.channel_ids.contains(&channel_id)
+            || (!allowed_channels.is_empty() && !allowed_channels.contains(&channel_id)) {
+            return Err(CommandRestrictionError::Channel);
+        }
+
+        let allowed_roles = &restriction.allowed_roles.role_ids;
+
+        if roles.iter().any(|role| restriction.denied_roles.role_ids.contains(&role.0))
+            || (!allowed_roles.is_empty() && !roles.iter().any(|role| allowed_roles.contains(&role.0))) {
+            return Err(CommandRestrictionError::Role);
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a restriction names the command, or a group the command is in.
+fn applies_to(restriction: &CommandRestriction, command: &str) -> bool {
+    command == restriction.command
+        || command.strip_prefix(restriction.command.as_str()).map_or(false, |rest| rest.starts_with(' '))
+}
+
+/// An error raised when a command is used where the restrictions of its guild do not allow it.
+#[derive(Debug, Copy, Clone)]
+crate enum CommandRestrictionError {
+    /// The command is disabled in the guild.
+    Disabled,
+
+    /// The command cannot be used in the channel.
+    Channel,
+
+    /// The command cannot be used with the roles of the member.
+    Role
+}
+
+impl Display for CommandRestrictionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Disabled => write!(f, "This command is disabled in this server."),
+            Self::Channel => write!(f, "This command cannot be used in this channel."),
+            Self::Role => write!(f, "You do not have a role that can use this command.")
+        }
+    }
+}
+
+impl Error for CommandRestrictionError {}
@@ -0,0 +1,51 @@
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    }
+};
+
+/// Where a command can be used; invocations elsewhere are rejected before the precommand checks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+crate enum CommandScope {
+    /// Only in the channels of a guild.
+    GuildOnly,
+
+    /// Only in direct messages with the bot.
+    #[allow(dead_code)]
+    DirectMessageOnly,
+
+    /// Both in guilds and in direct messages.
+    Anywhere
+}
+
+impl CommandScope {
+    /// Checks whether the command can be used in a guild, or in direct messages otherwise.
+    crate fn check(self, in_guild: bool) -> Result<(), CommandScopeError> {
+        match (self, in_guild) {
+            (Self::GuildOnly, false) => Err(CommandScopeError::GuildOnly),
+            (Self::DirectMessageOnly, true) => Err(CommandScopeError::DirectMessageOnly),
+            _ => Ok(())
+        }
+    }
+}
+
+/// An error raised when a command is used outside of its scope.
+#[derive(Debug, Copy, Clone)]
+crate enum CommandScopeError {
+    GuildOnly,
+    DirectMessageOnly
+}
+
+impl Display for CommandScopeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::GuildOnly => write!(f, "This command can only be used in a server."),
+            Self::DirectMessageOnly => write!(f, "This command can only be used in direct messages.")
+        }
+    }
+}
+
+impl Error for CommandScopeError {}
@@ -0,0 +1,101 @@
+use std::{
+    sync::Arc
+};
+
+use twilight_http::{
+    Client as HttpClient
+};
+
+use crate::command_system::{
+    events::emitter::CommandEventEmitter,
+    GuildConfigCache
+};
+
+#[cfg(test)]
+use crate::command_system::events::listener::Listeners;
+
+use crate::system::{
+    database::DatabasePools,
+    discord_api::DiscordApi,
+    stores::{
+        ConfigStore,
+        ErrorReportStore,
+        InfractionStore,
+        PostgresConfigStore,
+        PostgresErrorReportStore,
+        PostgresInfractionStore,
+        PostgresScheduledActionStore,
+        PostgresWhitelistStore,
+        WhitelistStore
+    },
+    scheduler::Scheduler
+};
+
+#[cfg(test)]
+use crate::system::{
+    discord_api::RecordingDiscordApi,
+    stores::{
+        InMemoryConfigStore,
+        InMemoryErrorReportStore,
+        InMemoryInfractionStore,
+        InMemoryScheduledActionStore,
+        InMemoryWhitelistStore
+    }
+};
+
+/// The Discord operations and stores that commands go through, along with the emitter of the events of what they
+/// did; tests replace them with in-memory fakes.
+#[derive(Clone)]
+crate struct CommandServices {
+    crate discord: Arc<dyn DiscordApi>,
+    crate infractions: Arc<dyn InfractionStore>,
+    crate configs: Arc<dyn ConfigStore>,
+    crate guild_configs: GuildConfigCache,
+    crate whitelist: Arc<dyn WhitelistStore>,
+    crate error_reports: Arc<dyn ErrorReportStore>,
+    crate scheduler: Scheduler,
+    crate emitter: CommandEventEmitter
+}
+
+impl CommandServices {
+    /// The services backed by Discord and the Postgres databases, which share the pools of the databases.
+    crate fn new(http_client: HttpClient, pools: DatabasePools, emitter: CommandEventEmitter) -> Self {
+        let discord: Arc<dyn DiscordApi> = Arc::new(http_client.clone());
+        let configs: Arc<dyn ConfigStore> = Arc::new(PostgresConfigStore::new(pools.guild_configuration,
+                                                                              emitter.clone()));
+
+        Self {
+            discord: discord.clone(),
+            infractions: Arc::new(PostgresInfractionStore::new(pools.guild_infractions.clone(), emitter.clone())),
+            configs: configs.clone(),
+            guild_configs: GuildConfigCache::new(configs.clone()),
+            whitelist: Arc::new(PostgresWhitelistStore::new(pools.guilds.clone(), http_client, configs.clone(),
+                                                            emitter.clone())),
+            error_reports: Arc::new(PostgresErrorReportStore::new(pools.guilds)),
+            scheduler: Scheduler::new(discord, Arc::new(PostgresScheduledActionStore::new(pools.guild_infractions)),
+                                      emitter.clone()),
+            emitter
+        }
+    }
+
+    /// The services backed by in-memory fakes, which tests keep clones of to inspect afterwards; events are only sent
+    /// to the subscribers of the emitter.
+    #[cfg(test)]
+    crate fn in_memory(discord: RecordingDiscordApi, infractions: InMemoryInfractionStore, configs: InMemoryConfigStore)
+        -> Self {
+        let discord: Arc<dyn DiscordApi> = Arc::new(discord);
+        let configs: Arc<dyn ConfigStore> = Arc::new(configs);
+        let emitter = CommandEventEmitter::new(Listeners::default());
+
+        Self {
+            discord: discord.clone(),
+            infractions: Arc::new(infractions),
+            configs: configs.clone(),
+            guild_configs: GuildConfigCache::new(configs),
+            whitelist: Arc::new(InMemoryWhitelistStore::new()),
+            error_reports: Arc::new(InMemoryErrorReportStore::new()),
+            scheduler: Scheduler::new(discord, Arc::new(InMemoryScheduledActionStore::new()), emitter.clone()),
+            emitter
+        }
+    }
+}
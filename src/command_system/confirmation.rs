@@ -0,0 +1,97 @@
+use std::{
+    time::Duration
+};
+
+use twilight_http::{
+    request::channel::reaction::RequestReactionType
+};
+
+use twilight_model::{
+    channel::ReactionType,
+    gateway::payload::ReactionAdd,
+    id::GuildId
+};
+
+use crate::{
+    command_system::{
+        CommandContext,
+        GuildConfigCache
+    },
+    logging::logger::Logger,
+    system::{
+        SystemResult
+    }
+};
+
+const CONFIRM_EMOJI: &str = "✅";
+const CANCEL_EMOJI: &str = "❌";
+
+/// How long the invoker has to react to a confirmation prompt before the command is cancelled.
+const CONFIRMATION_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Asks the invoker to confirm a destructive command by reacting to a summary of what it will do, such as
+/// "This will kick 143 members.", if the guild lists the command in its `CommandConfirmations` configuration.
+///
+/// Returns whether the command should continue; it does not when the invoker cancels or does not react in time.
+crate async fn confirm(ctx: &CommandContext<'_>, command: &str, summary: String) -> SystemResult<bool> {
+    if !requires_confirmation(&ctx.services.guild_configs, ctx.message.guild_id, command).await {
+        return Ok(true);
+    }
+
+    let prompt = ctx.http_client
+        .clone()
+        .create_message(ctx.message.channel_id)
+        .content(format!("{} React with {} to continue, or {} to cancel.", summary, CONFIRM_EMOJI, CANCEL_EMOJI))?
+        .allowed_mentions()
+        .replied_user(false)
+        .build()
+        .reply(ctx.message.id)
+        .await?;
+
+    for emoji in &[CONFIRM_EMOJI, CANCEL_EMOJI] {
+        ctx.http_client.clone().create_reaction(prompt.channel_id, prompt.id, RequestReactionType::Unicode {
+            name: emoji.to_string()
+        }).await?;
+    }
+
+    let author_id = ctx.author.id;
+    let reaction = ctx.standby.wait_for_reaction(prompt.id, move |reaction: &ReactionAdd| {
+        reaction.user_id == author_id && (is_emoji(&reaction.emoji, CONFIRM_EMOJI) || is_emoji(&reaction.emoji, CANCEL_EMOJI))
+    });
+
+    let content = match tokio::time::timeout(CONFIRMATION_TIMEOUT, reaction).await {
+        Ok(Ok(reaction)) if is_emoji(&reaction.emoji, CONFIRM_EMOJI) => return Ok(true),
+        Ok(_) => String::from("<:red_x:705623424675872859> Cancelled."),
+        Err(_) => format!(
+            "<:red_x:705623424675872859> Cancelled, as there was no reaction within {} seconds.", CONFIRMATION_TIMEOUT.as_secs())
+    };
+
+    ctx.http_client.clone().update_message(prompt.channel_id, prompt.id).content(content)?.await?;
+
+    Ok(false)
+}
+
+/// Whether the configuration of the guild lists the command as requiring confirmation; commands in direct messages
+/// never do, while commands in a guild whose configuration cannot be read always do, so that a destructive command is
+/// not run unconfirmed.
+async fn requires_confirmation(guild_configs: &GuildConfigCache, guild_id: Option<GuildId>, command: &str) -> bool {
+    let guild_id = match guild_id {
+        Some(guild_id) => guild_id,
+        None => return false
+    };
+
+    match guild_configs.config(guild_id).await {
+        Ok(config) => config.plugins.command_confirmations.commands.iter().any(|name| name == command),
+        Err(error) => {
+            Logger::log_warning(
+                format!("Could not read the configuration of guild {}; asking for confirmation. Error: {}",
+                        guild_id, error));
+
+            true
+        }
+    }
+}
+
+fn is_emoji(emoji: &ReactionType, expected: &str) -> bool {
+    matches!(emoji, ReactionType::Unicode { name } if name == expected)
+}
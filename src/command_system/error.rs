@@ -1,24 +1,108 @@
 use std::{
     error::Error,
     fmt::{
-        Debug as StdDebug,
         Display,
         Formatter,
         Result
     }
 };
 
-crate struct CommandError(crate String);
+use sha3::{
+    Digest,
+    Sha3_512
+};
 
-impl Display for CommandError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.0)
+use twilight_model::{
+    channel::Message
+};
+
+use crate::command_system::{
+    parser::ArgumentError,
+    precommand_checks::CooldownError
+};
+
+use crate::system::discord_api::UnknownTarget;
+
+/// The number of hexadecimal digits of an error code.
+const ERROR_CODE_LENGTH: usize = 12;
+
+/// An error raised while handling a command, classified by who can act on it.
+#[derive(Debug, Clone)]
+crate enum CommandError {
+    /// A mistake in the invocation of the command, such as an invalid ID, which the user can correct.
+    User(String),
+
+    /// The user is not allowed to use the command.
+    Permission(String),
+
+    /// A request to Discord failed.
+    Discord(String),
+
+    /// A query of the database failed.
+    Database(String),
+
+    /// A bug in the bot.
+    Internal(String)
+}
+
+impl CommandError {
+    /// Classifies any error raised while handling a command; errors other than a `CommandError` are classified by
+    /// their type, and an error of an unexpected type is a bug.
+    crate fn classify(error: &(dyn Error + Send + Sync + 'static)) -> Self {
+        if let Some(command_error) = error.downcast_ref::<CommandError>() {
+            command_error.clone()
+        }
+        else if error.is::<ArgumentError>() || error.is::<CooldownError>() {
+            Self::User(error.to_string())
+        }
+        else if error.is::<twilight_http::Error>() || error.is::<UnknownTarget>() {
+            Self::Discord(error.to_string())
+        }
+        else if error.is::<sqlx::Error>() || error.is::<tokio_postgres::Error>() {
+            Self::Database(error.to_string())
+        }
+        else {
+            Self::Internal(error.to_string())
+        }
+    }
+
+    /// The short code the user can provide in the support server to look up an internal error, which is stored under
+    /// it; other errors are explained to the user instead.
+    crate fn error_code(&self, message: &Message) -> Option<String> {
+        match self {
+            Self::Internal(error) => {
+                let hash = format!("{:x}",
+                    Sha3_512::digest(format!("{}{:?}{:?}", error, message.guild_id, message.id).as_bytes()));
+
+                Some(hash[..ERROR_CODE_LENGTH].to_uppercase())
+            },
+            _ => None
+        }
+    }
+
+    /// The reply to the message that invoked the command.
+    crate fn user_message(&self, message: &Message) -> String {
+        match self {
+            Self::User(error) | Self::Permission(error) => format!("<:red_x:705623424675872859> {}", error),
+            Self::Discord(_) => String::from(
+                "<:red_x:705623424675872859> Discord could not complete a request for this command; please try again later."),
+            Self::Database(_) => String::from(
+                "<:red_x:705623424675872859> The database could not complete a request for this command; please try again later."),
+            Self::Internal(_) => format!(
+                "Oops! This command raised an error. Please join or go to our Support Server (you need to get the **_HarTex** role, go to <#667597397215674368>) and provide the error code below for further troubleshooting and investigation.\n\nServer Invite: discord.gg/s8qjxZK\n\nError code: `{}`",
+                self.error_code(message).unwrap_or_default())
+        }
     }
 }
 
-impl StdDebug for CommandError {
+impl Display for CommandError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "Command Error: {}", self.0)
+        match self {
+            Self::User(error) | Self::Permission(error) => write!(f, "{}", error),
+            Self::Discord(error) => write!(f, "Discord error: {}", error),
+            Self::Database(error) => write!(f, "Database error: {}", error),
+            Self::Internal(error) => write!(f, "Internal error: {}", error)
+        }
     }
 }
 
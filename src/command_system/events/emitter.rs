@@ -1,9 +1,13 @@
 use super::{
-    events::SystemEvent,
+    events::{
+        CommandEvents,
+        SystemEvent,
+        SystemEventType
+    },
     listener::Listeners
 };
 
-/// Represents an event emitter.
+/// Represents an event emitter, which listeners can also subscribe through.
 #[derive(Clone)]
 crate struct CommandEventEmitter {
     listeners: Listeners<SystemEvent>
@@ -18,35 +22,38 @@ impl CommandEventEmitter {
     }
 
     /// Returns the emitters that this instance of the emitter has.
+    #[allow(dead_code)]
     crate fn into_listeners(self) -> Listeners<SystemEvent> {
         self.listeners
     }
 
-    /// Creates an event for the listeners
-    crate fn event(&self, event: SystemEvent) {
-        let listener_count = self.listeners.len();
-        let mut event = Some(event);
-
-        self.send(
-            |idx| {
-                if idx == listener_count {
-                    event.take().unwrap()
-                }
-                else {
-                    event.clone().unwrap()
-                }
-            }
-        )
+    /// Subscribes to every event; the subscription ends when the returned stream is dropped.
+    #[allow(dead_code)]
+    crate fn subscribe(&self) -> CommandEvents {
+        CommandEvents::new(self.listeners.clone(), |_| true)
     }
 
-    /// Sends the events created from the `event` method.
-    fn send(&self, mut f: impl FnMut(usize) -> SystemEvent) {
-        let mut idx = 0;
+    /// Subscribes to the events of the given types; the subscription ends when the returned stream is dropped.
+    #[allow(dead_code)]
+    crate fn subscribe_to(&self, event_types: &[SystemEventType]) -> CommandEvents {
+        let event_types = event_types.to_vec();
 
-        self.listeners.all().retain(|id, listener| {
-            idx += 1;
+        CommandEvents::new(self.listeners.clone(), move |event| event_types.contains(&event.event_type()))
+    }
 
-            listener.tx.unbounded_send(f(idx)).is_ok()
-        });
+    /// Sends an event to the listeners that accept it, waiting for room in the channel of any that are full.
+    crate async fn event(&self, event: SystemEvent) {
+        // The senders are collected first, so that the listeners are not locked while waiting.
+        let listeners = self.listeners.all().iter()
+            .filter(|listener| (listener.filter)(&event))
+            .map(|listener| (*listener.key(), listener.tx.clone()))
+            .collect::<Vec<_>>();
+
+        for (id, tx) in listeners {
+            // The receiver was dropped without unsubscribing, such as when its task panicked.
+            if tx.send(event.clone()).await.is_err() {
+                self.listeners.remove(id);
+            }
+        }
     }
 }
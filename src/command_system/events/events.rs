@@ -6,26 +6,45 @@ use std::{
     }
 };
 
-use futures_channel::mpsc::UnboundedReceiver;
 use futures_util::{
-    stream::Stream,
-    StreamExt
+    stream::Stream
 };
 
+use tokio::sync::mpsc::Receiver;
+
 use crate::system::model::payload::{
     CommandExecuted,
     CommandFailed,
-    CommandReceived
+    CommandReceived,
+    ConfigChanged,
+    GuildWhitelisted,
+    InfractionCreated,
+    InfractionRemoved,
+    InfractionUpdated,
+    InfractionsCleared,
+    LockdownEnded,
+    LockdownStarted,
+    MemberMuted,
+    MemberUnmuted
 };
 
-/// Represents some events.
+use super::listener::Listeners;
+
+/// Represents a subscription to some events, which unsubscribes when dropped.
 crate struct CommandEvents {
-    rx: UnboundedReceiver<SystemEvent>
+    id: u64,
+    listeners: Listeners<SystemEvent>,
+    rx: Receiver<SystemEvent>
 }
 
-impl<'a> CommandEvents {
-    crate fn new(rx: UnboundedReceiver<SystemEvent>) -> Self {
+impl CommandEvents {
+    crate fn new(listeners: Listeners<SystemEvent>, filter: impl Fn(&SystemEvent) -> bool + Send + Sync + 'static)
+        -> Self {
+        let (id, rx) = listeners.add(filter);
+
         Self {
+            id,
+            listeners,
             rx
         }
     }
@@ -35,7 +54,13 @@ impl Stream for CommandEvents {
     type Item = SystemEvent;
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        self.rx.poll_next_unpin(cx)
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl Drop for CommandEvents {
+    fn drop(&mut self) {
+        self.listeners.remove(self.id);
     }
 }
 
@@ -47,4 +72,59 @@ crate enum SystemEvent {
     CommandIdentified(String),
     CommandExecuted(Box<CommandExecuted>),
     CommandFailed(Box<CommandFailed>),
+
+    // Moderation Events
+    InfractionCreated(Box<InfractionCreated>),
+    InfractionRemoved(Box<InfractionRemoved>),
+    InfractionUpdated(Box<InfractionUpdated>),
+    InfractionsCleared(Box<InfractionsCleared>),
+    MemberMuted(Box<MemberMuted>),
+    MemberUnmuted(Box<MemberUnmuted>),
+    LockdownStarted(Box<LockdownStarted>),
+    LockdownEnded(Box<LockdownEnded>),
+
+    // Guild Lifecycle Events
+    GuildWhitelisted(Box<GuildWhitelisted>),
+    ConfigChanged(Box<ConfigChanged>)
+}
+
+impl SystemEvent {
+    crate fn event_type(&self) -> SystemEventType {
+        match self {
+            Self::CommandReceived(_) => SystemEventType::CommandReceived,
+            Self::CommandIdentified(_) => SystemEventType::CommandIdentified,
+            Self::CommandExecuted(_) => SystemEventType::CommandExecuted,
+            Self::CommandFailed(_) => SystemEventType::CommandFailed,
+            Self::InfractionCreated(_) => SystemEventType::InfractionCreated,
+            Self::InfractionRemoved(_) => SystemEventType::InfractionRemoved,
+            Self::InfractionUpdated(_) => SystemEventType::InfractionUpdated,
+            Self::InfractionsCleared(_) => SystemEventType::InfractionsCleared,
+            Self::MemberMuted(_) => SystemEventType::MemberMuted,
+            Self::MemberUnmuted(_) => SystemEventType::MemberUnmuted,
+            Self::LockdownStarted(_) => SystemEventType::LockdownStarted,
+            Self::LockdownEnded(_) => SystemEventType::LockdownEnded,
+            Self::GuildWhitelisted(_) => SystemEventType::GuildWhitelisted,
+            Self::ConfigChanged(_) => SystemEventType::ConfigChanged
+        }
+    }
+}
+
+/// The types of events, which listeners can subscribe to.
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+crate enum SystemEventType {
+    CommandReceived,
+    CommandIdentified,
+    CommandExecuted,
+    CommandFailed,
+    InfractionCreated,
+    InfractionRemoved,
+    InfractionUpdated,
+    InfractionsCleared,
+    MemberMuted,
+    MemberUnmuted,
+    LockdownStarted,
+    LockdownEnded,
+    GuildWhitelisted,
+    ConfigChanged
 }
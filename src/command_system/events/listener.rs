@@ -1,57 +1,75 @@
 use std::{
     sync::{
+        atomic::{
+            AtomicU64,
+            Ordering
+        },
         Arc
     }
 };
 
 use dashmap::DashMap;
 
-use futures_channel::mpsc::{
-    UnboundedReceiver,
-    UnboundedSender
+use tokio::sync::mpsc::{
+    self,
+    Receiver,
+    Sender
 };
 
-/// Represents an event listener.
-#[derive(Debug, Clone)]
+/// The number of unread events a listener can hold; emitting an event waits while a listener it is sent to is full.
+crate const LISTENER_CAPACITY: usize = 64;
+
+/// Represents an event listener, which only receives the events its filter accepts.
+#[derive(Clone)]
 crate struct Listener<T> {
-    crate tx: UnboundedSender<T>
+    crate tx: Sender<T>,
+    crate filter: Arc<dyn Fn(&T) -> bool + Send + Sync>
 }
 
-#[derive(Debug, Clone)]
 struct ListenersRef<T> {
-    id: u64,
+    next_id: AtomicU64,
     listeners: DashMap<u64, Listener<T>>
 }
 
 impl<T> Default for ListenersRef<T> {
     fn default() -> Self {
         Self {
-            id: 0,
+            next_id: AtomicU64::new(1),
             listeners: DashMap::new(),
         }
     }
 }
 
-/// Represents a series of listeners in `DashMap`s.
-#[derive(Debug, Clone)]
+/// Represents a series of listeners in `DashMap`s, each under a unique ID.
 crate struct Listeners<T>(Arc<ListenersRef<T>>);
 
 impl<T> Listeners<T> {
-    crate fn add(&self) -> UnboundedReceiver<T> {
-        let id = self.0.id + 1;
-        let (tx, rx) = futures_channel::mpsc::unbounded();
+    /// Adds a listener for the events the filter accepts, returning its ID and the receiver of its events.
+    crate fn add(&self, filter: impl Fn(&T) -> bool + Send + Sync + 'static) -> (u64, Receiver<T>) {
+        let id = self.0.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = mpsc::channel(LISTENER_CAPACITY);
+
+        self.0.listeners.insert(id, Listener {
+            tx,
+            filter: Arc::new(filter)
+        });
 
-        self.0.listeners.insert(id, Listener { tx });
+        (id, rx)
+    }
 
-        rx
+    /// Removes a listener, closing the channel of its events.
+    crate fn remove(&self, id: u64) {
+        self.0.listeners.remove(&id);
     }
 
     crate fn all(&self) -> &DashMap<u64, Listener<T>> {
         &self.0.listeners
     }
+}
 
-    crate fn len(&self) -> usize {
-        self.0.listeners.len()
+impl<T> Clone for Listeners<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
     }
 }
 
@@ -8,17 +8,22 @@ use crate::command_system::{
         },
         listener::Listeners
     },
+    hooks::CommandHook,
     parser::{
         CommandParser,
         CommandParserConfiguration,
     },
     Command,
+    CommandChecks,
+    CommandHandler,
+    CommandRegistry,
     cfg::{CaseSensitivity, UseFullyQualifiedName, EnabledAliases},
 };
 
 #[derive(Clone)]
 crate struct CommandFramework<'a> {
     command_parser_config: CommandParserConfiguration<'a>,
+    command_registry: CommandRegistry,
     listeners: Listeners<SystemEvent>
 }
 
@@ -26,25 +31,41 @@ impl<'a> CommandFramework<'a> {
     crate fn new() -> Self {
         Self {
             command_parser_config: CommandParserConfiguration::default(),
+            command_registry: CommandRegistry::new(),
             listeners: Listeners::default()
         }
     }
 
-    crate fn command<T>(mut self, command: T, case_sensitive: CaseSensitivity, fully_qualified_name: UseFullyQualifiedName, enable_aliases: EnabledAliases) -> Self
+    crate fn command<T>(mut self, command: T, case_sensitive: CaseSensitivity, fully_qualified_name: UseFullyQualifiedName, enable_aliases: EnabledAliases, checks: CommandChecks) -> Self
     where T: Command {
-        self
-            .command_parser_config
-            .add_command(
-                match fully_qualified_name {
-                    UseFullyQualifiedName::True => command.fully_qualified_name(),
-                    UseFullyQualifiedName::False => command.name(),
-                }, case_sensitive);
-
-        if matches!(enable_aliases, EnabledAliases::True) {
-            for alias in command.aliases() {
-                self.command_parser_config.add_command(alias, case_sensitive);
-            }
+        let name = match fully_qualified_name {
+            UseFullyQualifiedName::True => command.fully_qualified_name(),
+            UseFullyQualifiedName::False => command.name(),
+        };
+
+        // The parser matches the whole path, so subcommands are registered as e.g. `inf search`.
+        self.command_parser_config.add_command(name.clone(), case_sensitive);
+
+        let aliases = if matches!(enable_aliases, EnabledAliases::True) {
+            command.aliases()
         }
+        else {
+            Vec::new()
+        };
+
+        for alias in aliases.clone() {
+            self.command_parser_config.add_command(alias, case_sensitive);
+        }
+
+        self.command_registry.register(name, CommandHandler::new(&command, checks), aliases);
+
+        self
+    }
+
+    /// Registers a hook to run around every command, after the hooks registered before it.
+    crate fn hook<H>(mut self, _hook: H) -> Self
+    where H: CommandHook {
+        self.command_registry.add_hook::<H>();
 
         self
     }
@@ -59,13 +80,16 @@ impl<'a> CommandFramework<'a> {
         CommandParser::new(self.command_parser_config)
     }
 
+    crate fn build_registry(self) -> CommandRegistry {
+        self.command_registry
+    }
+
     crate fn listeners(self) -> Listeners<SystemEvent> {
         self.listeners
     }
 
+    /// Subscribes to every event; the subscription ends when the returned stream is dropped.
     crate fn events(self) -> CommandEvents {
-        let rx = self.listeners.add();
-
-        CommandEvents::new(rx)
+        CommandEvents::new(self.listeners, |_| true)
     }
 }
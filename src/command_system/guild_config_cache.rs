@@ -0,0 +1,145 @@
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant
+    }
+};
+
+use dashmap::DashMap;
+
+use twilight_model::{
+    id::GuildId
+};
+
+use crate::system::{
+    stores::ConfigStore,
+    SystemResult
+};
+
+use crate::xml_deserialization::{
+    BotConfig,
+    BotCustomization
+};
+
+/// How long a configuration is used before it is read again, as the dashboard changes configurations without the bot
+/// being told.
+const CONFIG_TTL: Duration = Duration::from_secs(300);
+
+struct CachedConfig {
+    read_at: Instant,
+    config: Arc<BotConfig>
+}
+
+/// Caches the parsed configuration of each guild, which the prefix, cooldowns and restrictions of every command are
+/// read from.
+///
+/// A configuration is read again once it is invalidated on `ConfigChanged`, or after `CONFIG_TTL`; a configuration that
+/// could not be read or parsed is not cached, so that it is read again the next time.
+#[derive(Clone)]
+crate struct GuildConfigCache {
+    store: Arc<dyn ConfigStore>,
+    configs: Arc<DashMap<GuildId, CachedConfig>>
+}
+
+impl GuildConfigCache {
+    crate fn new(store: Arc<dyn ConfigStore>) -> Self {
+        Self {
+            store,
+            configs: Arc::new(DashMap::new())
+        }
+    }
+
+    /// Resolves the configuration of a guild.
+    crate async fn config(&self, guild_id: GuildId) -> SystemResult<Arc<BotConfig>> {
+        if let Some(cached) = self.configs.get(&guild_id) && cached.read_at.elapsed() < CONFIG_TTL {
+            return Ok(cached.config.clone());
+        }
+
+        let config = self.store.guild_configuration(guild_id).await?;
+        let config = Arc::new(quick_xml::de::from_str::<BotConfig>(config.as_str())?);
+
+        self.configs.insert(guild_id, CachedConfig {
+            read_at: Instant::now(),
+            config: config.clone()
+        });
+
+        Ok(config)
+    }
+
+    /// Resolves the customization of a guild, such as its command prefix.
+    ///
+    /// Direct messages and guilds without a (valid) configuration use the default customization.
+    crate async fn customization(&self, guild_id: Option<GuildId>) -> BotCustomization {
+        match guild_id {
+            Some(guild_id) => self.config(guild_id).await
+                .map(|config| config.bot_customization.clone())
+                .unwrap_or_default(),
+            None => BotCustomization::default()
+        }
+    }
+
+    /// Forgets the configuration of a guild, so that it is read again the next time.
+    crate fn invalidate(&self, guild_id: GuildId) {
+        self.configs.remove(&guild_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::system::{
+        discord_api::fixtures::{
+            config,
+            GUILD_ID
+        },
+        stores::InMemoryConfigStore
+    };
+
+    use crate::utilities::constants::default_command_prefix;
+
+    use super::GuildConfigCache;
+
+    fn prefixed_config(prefix: &str) -> String {
+        config("").replace("<Plugins>", &format!(
+            "<BotCustomization><CommandPrefix>{}</CommandPrefix><GuildNickname>HarTex</GuildNickname></BotCustomization>\
+             <Plugins>", prefix))
+    }
+
+    #[tokio::test]
+    async fn does_not_cache_a_missing_configuration() {
+        let store = InMemoryConfigStore::new();
+        let cache = GuildConfigCache::new(Arc::new(store.clone()));
+
+        assert!(cache.config(GUILD_ID).await.is_err());
+
+        store.config(GUILD_ID, &prefixed_config("!"));
+
+        assert_eq!(cache.customization(Some(GUILD_ID)).await.command_prefix, "!");
+    }
+
+    #[tokio::test]
+    async fn uses_the_default_prefix_instead_of_a_blank_one() {
+        let store = InMemoryConfigStore::new().config(GUILD_ID, &prefixed_config(" "));
+        let cache = GuildConfigCache::new(Arc::new(store));
+
+        assert_eq!(cache.customization(Some(GUILD_ID)).await.command_prefix, default_command_prefix());
+    }
+
+    #[tokio::test]
+    async fn reads_an_invalidated_configuration_again() {
+        let store = InMemoryConfigStore::new().config(GUILD_ID, &prefixed_config("!"));
+        let cache = GuildConfigCache::new(Arc::new(store.clone()));
+
+        assert_eq!(cache.customization(Some(GUILD_ID)).await.command_prefix, "!");
+
+        store.config(GUILD_ID, &prefixed_config("?"));
+
+        assert_eq!(cache.customization(Some(GUILD_ID)).await.command_prefix, "!");
+
+        cache.invalidate(GUILD_ID);
+
+        assert_eq!(cache.customization(Some(GUILD_ID)).await.command_prefix, "?");
+    }
+}
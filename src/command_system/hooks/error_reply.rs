@@ -0,0 +1,82 @@
+use std::{
+    error::Error,
+    future::Future,
+    pin::Pin,
+    time::Duration
+};
+
+use twilight_http::{
+    Client as HttpClient
+};
+
+use twilight_model::{
+    channel::Message
+};
+
+use crate::{
+    command_system::{
+        CommandContext,
+        CommandError
+    },
+    logging::logger::Logger,
+    system::{
+        model::error_report::ErrorReport,
+        stores::ErrorReportStore,
+        SystemResult
+    }
+};
+
+use super::{
+    CommandHook,
+    CommandResult
+};
+
+/// Replies to a command that failed with the message of its error; only internal errors are given a code to provide in
+/// the support server.
+crate struct ErrorReply;
+
+impl CommandHook for ErrorReply {
+    fn after_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, command: String,
+                                          arguments: String, result: CommandResult, _elapsed: Duration)
+        -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(error_reply(ctx, command, arguments, result))
+    }
+}
+
+async fn error_reply(ctx: CommandContext<'_>, command: String, arguments: String, result: CommandResult)
+    -> SystemResult<()> {
+    match result {
+        Ok(()) => Ok(()),
+        Err(error) => reply_to_error(ctx.http_client.clone(), &*ctx.services.error_reports, command, arguments,
+                                     &ctx.message, &*error).await
+    }
+}
+
+/// Replies to the message that invoked a command with the message of an error it raised, after storing an internal
+/// error under its code.
+crate async fn reply_to_error(http_client: HttpClient, error_reports: &dyn ErrorReportStore, command: String,
+                              arguments: String, message: &Message, error: &(dyn Error + Send + Sync + 'static))
+    -> SystemResult<()> {
+    let command_error = CommandError::classify(error);
+
+    if let Some(code) = command_error.error_code(message) {
+        Logger::log_error(format!("Command '{}' raised an internal error with code {}: '{}'.", command, code, error));
+
+        // The code is shown to the user even if it could not be stored, as it still identifies the error in the logs.
+        let report = ErrorReport::new(code.clone(), command, arguments, message, error);
+
+        if let Err(report_error) = error_reports.add_error_report(report).await {
+            Logger::log_error(format!("Could not store the error report with code {}: '{}'.", code, report_error));
+        }
+    }
+
+    http_client.create_message(message.channel_id)
+        .content(command_error.user_message(message))?
+        .allowed_mentions()
+        .replied_user(false)
+        .build()
+        .reply(message.id)
+        .await?;
+
+    Ok(())
+}
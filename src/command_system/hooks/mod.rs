@@ -0,0 +1,69 @@
+use std::{
+    error::Error,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::Duration
+};
+
+mod error_reply;
+mod typing_indicator;
+
+crate use error_reply::{
+    reply_to_error,
+    ErrorReply
+};
+crate use typing_indicator::TypingIndicator;
+
+use crate::{
+    command_system::{
+        CommandContext
+    },
+    system::{
+        SystemResult
+    },
+    utilities::FutureResult
+};
+
+/// The result of a command as passed to the hooks run after it, which share its error.
+crate type CommandResult = Result<(), Arc<dyn Error + Send + Sync>>;
+
+/// The type-erased signature of `CommandHook::before_command`.
+crate type BeforeHookFunction = for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, String)
+    -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>>;
+
+/// The type-erased signature of `CommandHook::after_command`.
+crate type AfterHookFunction = for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, String, String,
+                                                           CommandResult, Duration)
+    -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>>;
+
+/// A hook run around every command that passes its checks, registered on the `CommandFramework`.
+crate trait CommandHook {
+    /// Runs before the command is executed, with its fully qualified name; an error stops the command from executing.
+    fn before_command<'asynchronous_trait>(_ctx: CommandContext<'asynchronous_trait>, _command: String)
+        -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(FutureResult::ok())
+    }
+
+    /// Runs after the command is executed, or stopped by a hook, with its arguments, its result and how long it took.
+    fn after_command<'asynchronous_trait>(_ctx: CommandContext<'asynchronous_trait>, _command: String,
+                                          _arguments: String, _result: CommandResult, _elapsed: Duration)
+        -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(FutureResult::ok())
+    }
+}
+
+/// The hooks registered on the framework, run in the order they were registered.
+#[derive(Clone, Default)]
+crate struct CommandHooks {
+    crate before: Vec<BeforeHookFunction>,
+    crate after: Vec<AfterHookFunction>
+}
+
+impl CommandHooks {
+    crate fn add<H>(&mut self)
+    where H: CommandHook {
+        self.before.push(H::before_command);
+        self.after.push(H::after_command);
+    }
+}
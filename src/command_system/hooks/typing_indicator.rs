@@ -0,0 +1,32 @@
+use std::{
+    future::Future,
+    pin::Pin
+};
+
+use crate::{
+    command_system::{
+        CommandContext
+    },
+    system::{
+        SystemResult
+    }
+};
+
+use super::CommandHook;
+
+/// Shows the bot as typing in the channel of a command while it executes.
+crate struct TypingIndicator;
+
+impl CommandHook for TypingIndicator {
+    fn before_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _command: String)
+        -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(typing_indicator(ctx))
+    }
+}
+
+async fn typing_indicator(ctx: CommandContext<'_>) -> SystemResult<()> {
+    // The indicator is only cosmetic, so failing to show it does not stop the command.
+    let _ = ctx.http_client.clone().create_typing_trigger(ctx.message.channel_id).await;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use twilight_model::{
+    channel::Message,
+    id::{
+        ChannelId,
+        GuildId,
+        MessageId,
+        UserId
+    }
+};
+
+/// Where and by whom a command was invoked, which is all of the invoking message that most commands read.
+#[derive(Debug, Copy, Clone)]
+crate struct Invocation {
+    crate guild_id: Option<GuildId>,
+    crate channel_id: ChannelId,
+    crate message_id: MessageId,
+    crate author_id: UserId
+}
+
+impl From<&Message> for Invocation {
+    fn from(message: &Message) -> Self {
+        Self {
+            guild_id: message.guild_id,
+            channel_id: message.channel_id,
+            message_id: message.id,
+            author_id: message.author.id
+        }
+    }
+}
@@ -1,23 +1,48 @@
 pub mod cfg;
 mod command;
+mod command_category;
 mod command_context;
+mod command_handler;
+mod command_registry;
+mod command_restrictions;
+mod command_services;
+mod command_scope;
+mod confirmation;
 mod error;
 crate mod events;
 mod execution_handler;
 mod framework;
+mod guild_config_cache;
+crate mod hooks;
+mod invocation;
 crate mod parser;
 pub mod precommand_checks;
 mod precommand_check_parameters;
+mod response_tracker;
 
 crate use command::Command;
+crate use command_category::CommandCategory;
 crate use command_context::{
     CommandContext,
     CommandContextRef
 };
+crate use command_handler::{
+    CommandChecks,
+    CommandFunction,
+    CommandHandler,
+    PrecommandCheckFunction
+};
+crate use command_registry::CommandRegistry;
+crate use command_scope::CommandScope;
+crate use command_services::CommandServices;
+crate use confirmation::confirm;
 crate use error::CommandError;
 crate use execution_handler::ExecutionHandler;
 crate use framework::CommandFramework;
+crate use guild_config_cache::GuildConfigCache;
+crate use invocation::Invocation;
 crate use precommand_check_parameters::{
     PrecommandCheckParameters,
     PrecommandCheckParametersBuilder
 };
+crate use response_tracker::ResponseTracker;
@@ -0,0 +1,40 @@
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    }
+};
+
+/// An error raised when extracting a typed argument from the command arguments.
+#[derive(Debug, Clone)]
+crate enum ArgumentError {
+    /// The argument is required but was not provided.
+    Missing {
+        expected: &'static str
+    },
+
+    /// The argument was provided but could not be parsed as the expected type.
+    Invalid {
+        expected: &'static str,
+        value: String
+    },
+
+    /// The flag takes a value, but none followed it.
+    MissingFlagValue {
+        flag: &'static str
+    }
+}
+
+impl Display for ArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Missing { expected } => write!(f, "Missing argument: expected {}.", expected),
+            Self::Invalid { expected, value } => write!(f, "Invalid argument `{}`: expected {}.", value, expected),
+            Self::MissingFlagValue { flag } => write!(f, "Missing value for the `--{}` flag.", flag)
+        }
+    }
+}
+
+impl Error for ArgumentError {}
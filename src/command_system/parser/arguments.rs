@@ -3,15 +3,32 @@ use std::{
         Debug,
         Formatter,
         Result as FmtResult
-    },
-    str::CharIndices,
+    }
+};
+
+use super::{
+    ArgumentError,
+    Flag,
+    Flags,
+    FromArgument
 };
 
+/// A single argument, along with the span of the arguments buffer it was read from.
+#[derive(Clone, Debug)]
+struct Token {
+    value: String,
+    quoted: bool,
+    start: usize,
+    end: usize
+}
+
 #[derive(Clone)]
 crate struct Arguments<'a> {
     buf: &'a str,
-    indices: CharIndices<'a>,
+    tokens: Vec<Token>,
     idx: usize,
+    /// The spans of the flags extracted by `flags`, which `rest` leaves out.
+    removed: Vec<(usize, usize)>
 }
 
 impl<'a> Arguments<'a> {
@@ -19,22 +36,189 @@ impl<'a> Arguments<'a> {
         Self::from(buf)
     }
 
-    #[allow(dead_code)]
     crate fn as_str(&self) -> &str {
         self.buf
     }
 
     crate fn into_remainder(self) -> Option<&'a str> {
-        self.buf.get(self.idx..)
+        let token = self.tokens.get(self.idx)?;
+
+        self.buf.get(token.start..)
+    }
+
+    /// Extracts the given flags from the remaining arguments, wherever they are; the other arguments are left in
+    /// order.
+    ///
+    /// Flags are only recognized unquoted, and a bare `--` stops looking for flags in the arguments after it.
+    crate fn flags(&mut self, flags: &[Flag]) -> Result<Flags, ArgumentError> {
+        let mut extracted = Flags::default();
+        let mut idx = self.idx;
+
+        while let Some(token) = self.tokens.get(idx) {
+            if token.quoted {
+                idx += 1;
+                continue;
+            }
+
+            if token.value == "--" {
+                self.remove(idx);
+                break;
+            }
+
+            let (flag, inline_value) = match token.value.strip_prefix("--") {
+                Some(long) => {
+                    let (name, value) = match long.split_once('=') {
+                        Some((name, value)) => (name, Some(value.to_string())),
+                        None => (long, None)
+                    };
+
+                    (flags.iter().find(|flag| flag.name == name), value)
+                },
+                None => {
+                    let mut chars = token.value.chars();
+                    let short = match (chars.next(), chars.next(), chars.next()) {
+                        (Some('-'), Some(short), None) => Some(short),
+                        _ => None
+                    };
+
+                    (flags.iter().find(|flag| flag.short.is_some() && flag.short == short), None)
+                }
+            };
+
+            let flag = match flag {
+                Some(flag) => *flag,
+                None => {
+                    idx += 1;
+                    continue;
+                }
+            };
+
+            self.remove(idx);
+
+            let value = if !flag.takes_value {
+                None
+            }
+            else if inline_value.is_some() {
+                inline_value
+            }
+            else if idx < self.tokens.len() {
+                Some(self.remove(idx).value)
+            }
+            else {
+                return Err(ArgumentError::MissingFlagValue {
+                    flag: flag.name
+                });
+            };
+
+            extracted.insert(flag.name, value);
+        }
+
+        Ok(extracted)
+    }
+
+    /// Extracts the next argument as the given type, failing if it is missing or does not parse.
+    crate fn next_as<T: FromArgument>(&mut self) -> Result<T, ArgumentError> {
+        match self.next() {
+            Some(argument) => T::parse_argument(&argument),
+            None => Err(ArgumentError::Missing {
+                expected: T::EXPECTED
+            })
+        }
+    }
+
+    /// Extracts the next argument as the given type if one was provided.
+    crate fn next_as_optional<T: FromArgument>(&mut self) -> Result<Option<T>, ArgumentError> {
+        match self.next() {
+            Some(argument) => T::parse_argument(&argument).map(Some),
+            None => Ok(None)
+        }
+    }
+
+    /// Extracts arguments as the given type until one does not parse; that argument is left unconsumed.
+    crate fn next_as_many<T: FromArgument>(&mut self) -> Result<Vec<T>, ArgumentError> {
+        let mut values = Vec::new();
+
+        while let Some(value) = self.tokens.get(self.idx).and_then(|token| T::from_argument(&token.value)) {
+            values.push(value);
+            self.idx += 1;
+        }
+
+        if values.is_empty() {
+            return match self.tokens.get(self.idx) {
+                Some(token) => Err(ArgumentError::Invalid {
+                    expected: T::EXPECTED,
+                    value: token.value.clone()
+                }),
+                None => Err(ArgumentError::Missing {
+                    expected: T::EXPECTED
+                })
+            };
+        }
+
+        Ok(values)
+    }
+
+    /// Consumes the rest of the arguments as a single string, as written apart from any extracted flags.
+    ///
+    /// If the rest is a single quoted argument or code block, its contents are returned instead.
+    crate fn rest(&mut self) -> Option<String> {
+        let remaining = self.tokens.get(self.idx..).unwrap_or_default();
+
+        self.idx = self.tokens.len();
+
+        let rest = match remaining {
+            [] => return None,
+            [token] if token.quoted => token.value.trim().to_string(),
+            [first, ..] => {
+                let mut pieces = Vec::new();
+                let mut cursor = first.start;
+
+                for &(start, end) in self.removed.iter().filter(|(start, _)| *start >= first.start) {
+                    pieces.push(&self.buf[cursor..start]);
+                    cursor = end;
+                }
+
+                pieces.push(&self.buf[cursor..]);
+
+                pieces.iter()
+                    .map(|piece| piece.trim())
+                    .filter(|piece| !piece.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+        };
+
+        if rest.is_empty() {
+            None
+        } else {
+            Some(rest)
+        }
+    }
+
+    /// Consumes the rest of the arguments as a single string, or returns the default if there are none.
+    crate fn rest_or(&mut self, default: &str) -> String {
+        self.rest().unwrap_or_else(|| default.to_string())
+    }
+
+    fn remove(&mut self, idx: usize) -> Token {
+        let token = self.tokens.remove(idx);
+
+        self.removed.push((token.start, token.end));
+        self.removed.sort_unstable();
+
+        token
     }
 }
 
 impl<'a> From<&'a str> for Arguments<'a> {
     fn from(buf: &'a str) -> Self {
+        let buf = buf.trim();
+
         Self {
-            buf: buf.trim(),
-            indices: buf.trim().char_indices(),
+            buf,
+            tokens: tokenize(buf),
             idx: 0,
+            removed: Vec::new()
         }
     }
 }
@@ -43,57 +227,138 @@ impl<'a> Debug for Arguments<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Arguments")
             .field("buf", &self.buf)
+            .field("tokens", &self.tokens)
             .field("idx", &self.idx)
             .finish()
     }
 }
 
 impl<'a> Iterator for Arguments<'a> {
-    type Item = &'a str;
+    type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx > self.buf.len() {
-            return None;
-        }
+        let token = self.tokens.get(self.idx)?;
 
-        let mut start_idx = self.idx;
-        let mut quoted = false;
-        let mut started = false;
+        self.idx += 1;
 
-        while let Some((i, ch)) = self.indices.next() {
-            if quoted {
-                if ch == '"' {
-                    let v = self.buf.get(start_idx..i);
-                    self.idx = i + 1;
+        Some(token.value.clone())
+    }
+}
 
-                    return v.map(str::trim);
-                }
-            } else if ch == ' ' {
-                if started {
-                    let v = self.buf.get(start_idx..i);
-                    self.idx = i + 1;
-
-                    return v.map(str::trim);
-                } else {
-                    self.idx = i;
-                    start_idx = i;
-                    started = true;
-                    continue;
-                }
-            } else if ch == '"' {
-                start_idx = i + 1;
-                quoted = true;
-            }
+/// Splits the arguments on any whitespace, keeping quoted arguments and code blocks together.
+///
+/// Arguments may be quoted with double, single or smart quotes, and a backslash escapes a quote, a backslash or
+/// whitespace. Quotes and code blocks that are not closed are read as plain arguments.
+fn tokenize(buf: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
 
-            self.idx = i;
-            started = true;
+    while let Some(offset) = buf[position..].find(|ch: char| !ch.is_whitespace()) {
+        let start = position + offset;
+        let token = code_block(buf, start)
+            .or_else(|| quoted(buf, start))
+            .unwrap_or_else(|| plain(buf, start));
+
+        position = token.end;
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+fn code_block(buf: &str, start: usize) -> Option<Token> {
+    if !buf[start..].starts_with("```") {
+        return None;
+    }
+
+    let content_start = start + 3;
+    let content_end = content_start + buf[content_start..].find("```")?;
+    let content = &buf[content_start..content_end];
+
+    // The opening fence may be followed by a language on its own line, which is not part of the code.
+    let content = match content.split_once('\n') {
+        Some((language, code)) if !language.trim().is_empty() && !language.trim().contains(char::is_whitespace) => code,
+        _ => content
+    };
+
+    Some(Token {
+        value: content.trim_matches(|ch| ch == '\n' || ch == '\r').to_string(),
+        quoted: true,
+        start,
+        end: content_end + 3
+    })
+}
+
+fn quoted(buf: &str, start: usize) -> Option<Token> {
+    let open = buf[start..].chars().next()?;
+    let close = closing_quote(open)?;
+    let content_start = start + open.len_utf8();
+
+    let mut value = String::new();
+    let mut chars = buf[content_start..].char_indices().peekable();
+
+    while let Some((offset, ch)) = chars.next() {
+        if ch == '\\' && let Some(&(_, escaped)) = chars.peek() && is_escapable(escaped) {
+            value.push(escaped);
+            chars.next();
+        }
+        else if ch == close {
+            return Some(Token {
+                value,
+                quoted: true,
+                start,
+                end: content_start + offset + ch.len_utf8()
+            });
+        }
+        else {
+            value.push(ch);
         }
+    }
+
+    None
+}
 
-        self.idx = usize::max_value();
+fn plain(buf: &str, start: usize) -> Token {
+    let mut value = String::new();
+    let mut chars = buf[start..].char_indices().peekable();
 
-        match self.buf.get(start_idx..) {
-            Some("") | None => None,
-            Some(v) => Some(v.trim()),
+    while let Some((offset, ch)) = chars.next() {
+        if ch.is_whitespace() {
+            return Token {
+                value,
+                quoted: false,
+                start,
+                end: start + offset
+            };
+        }
+
+        if ch == '\\' && let Some(&(_, escaped)) = chars.peek() && is_escapable(escaped) {
+            value.push(escaped);
+            chars.next();
+        }
+        else {
+            value.push(ch);
         }
     }
-}
\ No newline at end of file
+
+    Token {
+        value,
+        quoted: false,
+        start,
+        end: buf.len()
+    }
+}
+
+fn closing_quote(open: char) -> Option<char> {
+    match open {
+        '"' => Some('"'),
+        '\'' => Some('\''),
+        '“' => Some('”'),
+        '‘' => Some('’'),
+        _ => None
+    }
+}
+
+fn is_escapable(ch: char) -> bool {
+    ch == '\\' || ch.is_whitespace() || matches!(ch, '"' | '\'' | '“' | '”' | '‘' | '’')
+}
@@ -6,6 +6,16 @@ crate enum CaseSensitive {
     False(String)
 }
 
+impl CaseSensitive {
+    /// Compares a segment of this command's path with a word of the input, respecting the case sensitivity.
+    crate fn segment_eq(&self, segment: &str, word: &str) -> bool {
+        match self {
+            Self::True(_) => UniCase::new(segment) == UniCase::new(word),
+            Self::False(_) => segment == word
+        }
+    }
+}
+
 impl AsRef<str> for CaseSensitive {
     fn as_ref(&self) -> &str {
         match self {
@@ -0,0 +1,56 @@
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    }
+};
+
+/// An error raised when a message cannot be parsed into a registered command.
+#[derive(Debug, Clone)]
+crate enum CommandParseError {
+    /// The message does not start with the prefix, or has nothing after it.
+    NoCommand,
+
+    /// The message starts with the prefix, but not with a registered command.
+    UnknownCommand {
+        name: String,
+        suggestions: Vec<String>
+    },
+
+    /// The message starts with a command group, but not with one of its subcommands.
+    UnknownSubcommand {
+        command: String,
+        subcommand: Option<String>,
+        available: Vec<String>
+    }
+}
+
+impl Display for CommandParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NoCommand => write!(f, "No command."),
+            Self::UnknownCommand { name, suggestions } => {
+                match suggestions.first() {
+                    Some(suggestion) => write!(f, "Unknown command `{}`; did you mean `{}`?", name, suggestion),
+                    None => write!(f, "Unknown command `{}`.", name)
+                }
+            },
+            Self::UnknownSubcommand { command, subcommand, available } => {
+                let available = available.iter()
+                    .map(|subcommand| format!("`{}`", subcommand))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match subcommand {
+                    Some(subcommand) => write!(f, "Unknown subcommand `{}` for `{}`; available subcommands: {}.",
+                                               subcommand, command, available),
+                    None => write!(f, "Missing subcommand for `{}`; available subcommands: {}.", command, available)
+                }
+            }
+        }
+    }
+}
+
+impl Error for CommandParseError {}
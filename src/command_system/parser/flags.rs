@@ -0,0 +1,68 @@
+use std::{
+    collections::HashMap
+};
+
+use super::{
+    ArgumentError,
+    FromArgument
+};
+
+/// A flag accepted by a command, such as the `--silent` switch or the `--days 7` option.
+#[derive(Debug, Copy, Clone)]
+crate struct Flag {
+    crate name: &'static str,
+    crate short: Option<char>,
+    crate takes_value: bool
+}
+
+impl Flag {
+    /// A flag that is either present or not, such as `--silent`.
+    crate const fn switch(name: &'static str) -> Self {
+        Self {
+            name,
+            short: None,
+            takes_value: false
+        }
+    }
+
+    /// A flag followed by a value, such as `--days 7` or `--days=7`.
+    crate const fn option(name: &'static str) -> Self {
+        Self {
+            name,
+            short: None,
+            takes_value: true
+        }
+    }
+
+    /// Also accepts the flag by a single character, such as `-s`.
+    crate const fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+
+        self
+    }
+}
+
+/// The flags extracted from the command arguments, keyed by their names.
+#[derive(Debug, Clone, Default)]
+crate struct Flags {
+    values: HashMap<&'static str, Option<String>>
+}
+
+impl Flags {
+    crate fn insert(&mut self, name: &'static str, value: Option<String>) {
+        self.values.insert(name, value);
+    }
+
+    /// Whether the flag was provided.
+    crate fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Extracts the value of an option as the given type if it was provided.
+    crate fn value_as<T: FromArgument>(&self, name: &str) -> Result<Option<T>, ArgumentError> {
+        match self.values.get(name) {
+            Some(Some(value)) => T::parse_argument(value).map(Some),
+            _ => Ok(None)
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::{
+    time::Duration
+};
+
+use twilight_mention::{
+    ParseMention
+};
+
+use twilight_model::{
+    id::{
+        ChannelId,
+        RoleId,
+        UserId
+    }
+};
+
+use crate::utilities::duration::parse_duration;
+
+use super::ArgumentError;
+
+/// Represents a type that can be extracted from a single command argument.
+crate trait FromArgument: Sized {
+    /// A description of the expected argument, used in parse errors.
+    const EXPECTED: &'static str;
+
+    fn from_argument(argument: &str) -> Option<Self>;
+
+    fn parse_argument(argument: &str) -> Result<Self, ArgumentError> {
+        Self::from_argument(argument).ok_or_else(|| ArgumentError::Invalid {
+            expected: Self::EXPECTED,
+            value: argument.to_string()
+        })
+    }
+}
+
+impl FromArgument for UserId {
+    const EXPECTED: &'static str = "a user mention or user ID";
+
+    fn from_argument(argument: &str) -> Option<Self> {
+        UserId::parse(argument).ok().or_else(|| argument.parse().ok().map(UserId))
+    }
+}
+
+impl FromArgument for ChannelId {
+    const EXPECTED: &'static str = "a channel mention or channel ID";
+
+    fn from_argument(argument: &str) -> Option<Self> {
+        ChannelId::parse(argument).ok().or_else(|| argument.parse().ok().map(ChannelId))
+    }
+}
+
+impl FromArgument for RoleId {
+    const EXPECTED: &'static str = "a role mention or role ID";
+
+    fn from_argument(argument: &str) -> Option<Self> {
+        RoleId::parse(argument).ok().or_else(|| argument.parse().ok().map(RoleId))
+    }
+}
+
+impl FromArgument for Duration {
+    const EXPECTED: &'static str = "a duration such as `10m` or `1d12h`";
+
+    fn from_argument(argument: &str) -> Option<Self> {
+        parse_duration(argument)
+    }
+}
+
+impl FromArgument for String {
+    const EXPECTED: &'static str = "some text";
+
+    fn from_argument(argument: &str) -> Option<Self> {
+        Some(argument.to_string())
+    }
+}
+
+macro_rules! impl_from_argument_for_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl FromArgument for $integer {
+                const EXPECTED: &'static str = "a whole number";
+
+                fn from_argument(argument: &str) -> Option<Self> {
+                    argument.parse().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_argument_for_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
@@ -1,14 +1,34 @@
+mod argument_error;
 mod arguments;
 mod case_sensitive;
+mod command_parse_error;
 mod configuration;
+mod flags;
+mod from_argument;
 
+crate use argument_error::ArgumentError;
 crate use arguments::Arguments;
+crate use command_parse_error::CommandParseError;
 crate use configuration::CommandParserConfiguration;
+crate use flags::{
+    Flag,
+    Flags
+};
+crate use from_argument::FromArgument;
+
+use case_sensitive::CaseSensitive;
+
+use crate::utilities::levenshtein::levenshtein_distance;
+
+use twilight_model::{
+    id::UserId
+};
 
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 crate struct Command<'a> {
     pub arguments: Arguments<'a>,
+    /// The full path of the command as registered, such as `inf search`.
     pub name: &'a str,
     pub prefix: &'a str,
 }
@@ -35,47 +55,126 @@ impl<'a> CommandParser<'a> {
         &mut self.config
     }
 
-    crate fn parse(&'a self, buf: &'a str) -> Option<Command<'a>> {
-        let prefix = self.find_prefix(buf)?;
+    #[allow(dead_code)]
+    crate fn parse(&'a self, buf: &'a str, guild_prefix: &str, current_user_id: Option<UserId>)
+        -> Result<Command<'a>, CommandParseError> {
+        let prefix = self.find_prefix(buf, guild_prefix, current_user_id).ok_or(CommandParseError::NoCommand)?;
 
         self.parse_with_prefix(prefix, buf)
     }
 
-    crate fn parse_with_prefix(&'a self, prefix: &'a str, buf: &'a str) -> Option<Command<'a>> {
+    crate fn parse_with_prefix(&'a self, prefix: &'a str, buf: &'a str) -> Result<Command<'a>, CommandParseError> {
         if !buf.starts_with(prefix) {
-            return None;
+            return Err(CommandParseError::NoCommand);
         }
 
-        let mut idx = prefix.len();
-        let command_buf = buf.get(idx..)?;
-        let command = self.find_command(command_buf)?;
-
-        idx += command.len();
-
-        idx += command_buf.len() - command_buf.trim_start().len();
+        let command_buf = buf.get(prefix.len()..).ok_or(CommandParseError::NoCommand)?;
+        let (command, idx) = self.find_command(command_buf)?;
 
-        Some(Command {
-            arguments: Arguments::new(buf.get(idx..)?),
+        Ok(Command {
+            arguments: Arguments::new(command_buf.get(idx..).unwrap_or_default()),
             name: command,
             prefix,
         })
     }
 
-    fn find_command(&'a self, buf: &'a str) -> Option<&'a str> {
-        let buf = buf.split_whitespace().next()?;
-        self.config.commands.iter().find_map(|command| {
-            if command == buf {
-                Some(command.as_ref())
-            } else {
-                None
+    /// Finds the longest registered command path the buffer starts with, returning it along with the index at which
+    /// its arguments start.
+    ///
+    /// If the buffer enters a command group further than any registered command it matches, the subcommand is unknown.
+    fn find_command(&'a self, buf: &'a str) -> Result<(&'a str, usize), CommandParseError> {
+        let words = buf.split_whitespace()
+            .map(|word| (word, word.as_ptr() as usize - buf.as_ptr() as usize + word.len()))
+            .collect::<Vec<_>>();
+
+        let mut matched: Option<(&CaseSensitive, usize)> = None;
+        let mut group: Option<(&CaseSensitive, usize)> = None;
+
+        for command in &self.config.commands {
+            let path = command.as_ref().split_whitespace().collect::<Vec<_>>();
+            let depth = path.iter()
+                .zip(words.iter())
+                .take_while(|(segment, (word, _))| command.segment_eq(segment, word))
+                .count();
+
+            if depth == path.len() && matched.map_or(true, |(_, longest)| depth > longest) {
+                matched.replace((command, depth));
             }
-        })
+
+            if depth < path.len() && depth > 0 && group.map_or(true, |(_, deepest)| depth > deepest) {
+                group.replace((command, depth));
+            }
+        }
+
+        match (matched, group) {
+            (Some((command, depth)), Some((_, group_depth))) if depth >= group_depth => {
+                Ok((command.as_ref(), words[depth - 1].1))
+            },
+            (Some((command, depth)), None) => Ok((command.as_ref(), words[depth - 1].1)),
+            (_, Some((command, depth))) => {
+                let group_path = command.as_ref().split_whitespace().take(depth).collect::<Vec<_>>();
+                let mut available = self.config.commands.iter()
+                    .filter_map(|command| {
+                        let path = command.as_ref().split_whitespace().collect::<Vec<_>>();
+
+                        if path.len() > depth && path[..depth] == group_path[..] {
+                            Some(path[depth].to_string())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                available.sort();
+                available.dedup();
+
+                Err(CommandParseError::UnknownSubcommand {
+                    command: group_path.join(" "),
+                    subcommand: words.get(depth).map(|(word, _)| word.to_string()),
+                    available
+                })
+            },
+            (None, None) => match words.first() {
+                Some((word, _)) => Err(CommandParseError::UnknownCommand {
+                    name: word.to_string(),
+                    suggestions: self.suggest_commands(word)
+                }),
+                None => Err(CommandParseError::NoCommand)
+            }
+        }
     }
 
-    fn find_prefix(&self, buf: &str) -> Option<&str> {
-        self.config.command_prefixes.iter().find_map(|prefix| {
-            if buf.starts_with(prefix.as_ref()) {
-                Some(prefix.as_ref())
+    /// Finds the registered commands closest to an unknown command name by edit distance, closest first.
+    fn suggest_commands(&self, name: &str) -> Vec<String> {
+        let name = name.to_lowercase();
+        let threshold = (name.chars().count() / 3).max(2);
+
+        let mut candidates = self.config.commands.iter()
+            .filter_map(|command| command.as_ref().split_whitespace().next())
+            .map(|root| (levenshtein_distance(&name, &root.to_lowercase()), root.to_string()))
+            .filter(|(distance, _)| *distance <= threshold)
+            .collect::<Vec<_>>();
+
+        candidates.sort();
+        candidates.dedup();
+
+        candidates.into_iter()
+            .map(|(_, root)| root)
+            .take(3)
+            .collect()
+    }
+
+    /// Finds the prefix a message starts with: the prefix of the guild, or a mention of the bot.
+    crate fn find_prefix<'b>(&self, buf: &'b str, guild_prefix: &str, current_user_id: Option<UserId>) -> Option<&'b str> {
+        if buf.starts_with(guild_prefix) {
+            return buf.get(..guild_prefix.len());
+        }
+
+        let current_user_id = current_user_id?;
+
+        [format!("<@{}>", current_user_id), format!("<@!{}>", current_user_id)].iter().find_map(|mention| {
+            if buf.starts_with(mention.as_str()) {
+                buf.get(..mention.len())
             } else {
                 None
             }
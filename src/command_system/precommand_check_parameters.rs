@@ -2,6 +2,8 @@ use twilight_cache_inmemory::{
     InMemoryCache
 };
 
+use crate::command_system::precommand_checks::Cooldown;
+
 use twilight_model::{
     id::{
         UserId,
@@ -16,7 +18,9 @@ crate struct PrecommandCheckParameters {
     pub guild_id: Option<GuildId>,
     pub role_id: Option<RoleId>,
     pub cache: Option<InMemoryCache>,
-    pub minimum_permission_level: Option<u32>
+    pub minimum_permission_level: Option<u32>,
+    pub command_name: Option<String>,
+    pub cooldown: Option<Cooldown>
 }
 
 impl PrecommandCheckParameters {
@@ -32,7 +36,9 @@ crate struct PrecommandCheckParametersBuilder {
     pub guild_id: Option<GuildId>,
     pub role_id: Option<RoleId>,
     pub cache: Option<InMemoryCache>,
-    pub minimum_permission_level: Option<u32>
+    pub minimum_permission_level: Option<u32>,
+    pub command_name: Option<String>,
+    pub cooldown: Option<Cooldown>
 }
 
 impl PrecommandCheckParametersBuilder {
@@ -40,7 +46,6 @@ impl PrecommandCheckParametersBuilder {
         Self::default()
     }
 
-    #[allow(dead_code)]
     crate fn user_id(mut self, user_id: UserId) -> Self {
         self.user_id.replace(user_id);
 
@@ -65,13 +70,27 @@ impl PrecommandCheckParametersBuilder {
         self
     }
 
+    crate fn command_name(mut self, command_name: String) -> Self {
+        self.command_name.replace(command_name);
+
+        self
+    }
+
+    crate fn cooldown(mut self, cooldown: Cooldown) -> Self {
+        self.cooldown.replace(cooldown);
+
+        self
+    }
+
     crate fn build(self) -> PrecommandCheckParameters {
         PrecommandCheckParameters {
             user_id: self.user_id,
             guild_id: self.guild_id,
             role_id: self.role_id,
             cache: self.cache,
-            minimum_permission_level: self.minimum_permission_level
+            minimum_permission_level: self.minimum_permission_level,
+            command_name: self.command_name,
+            cooldown: self.cooldown
         }
     }
 }
@@ -83,7 +102,9 @@ impl Default for PrecommandCheckParametersBuilder {
             guild_id: None,
             role_id: None,
             cache: None,
-            minimum_permission_level: None
+            minimum_permission_level: None,
+            command_name: None,
+            cooldown: None
         }
     }
 }
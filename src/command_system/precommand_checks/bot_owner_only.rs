@@ -19,6 +19,8 @@ use super::PrecommandCheck;
 crate struct BotOwnerOnly;
 
 impl PrecommandCheck for BotOwnerOnly {
+    const HIDES_FROM_HELP: bool = true;
+
     fn execute_check<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                           _params: PrecommandCheckParameters)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
@@ -31,7 +33,7 @@ async fn bot_owner_only(ctx: CommandContext<'asynchronous_trait>) -> SystemResul
         Ok(())
     }
     else {
-        Err(box CommandError("Not bot owner.".to_string()))
+        Err(box CommandError::Permission("Only the owner of the bot can use this command.".to_string()))
     }
 }
 
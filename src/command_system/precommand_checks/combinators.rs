@@ -0,0 +1,102 @@
+use std::{
+    future::Future,
+    pin::Pin
+};
+
+use crate::{
+    command_system::{
+        CommandContext,
+        CommandError,
+        PrecommandCheckFunction,
+        PrecommandCheckParameters
+    },
+    system::{
+        SystemResult
+    }
+};
+
+use super::PrecommandCheck;
+
+/// Represents a tuple of precommand checks, combined by `All` or `Any`.
+///
+/// The combinators only hold their checks to infer their types, e.g. `All((BotOwnerOnly, SupportGuildOnly))`.
+crate trait PrecommandCheckList {
+    /// Whether any of the checks hides the commands a user fails it for from `help`.
+    const HIDES_FROM_HELP: bool;
+
+    fn checks() -> Vec<PrecommandCheckFunction>;
+}
+
+macro_rules! impl_precommand_check_list {
+    ($($check:ident),+) => {
+        impl<$($check: PrecommandCheck),+> PrecommandCheckList for ($($check,)+) {
+            const HIDES_FROM_HELP: bool = $($check::HIDES_FROM_HELP)||+;
+
+            fn checks() -> Vec<PrecommandCheckFunction> {
+                vec![$($check::execute_check),+]
+            }
+        }
+    };
+}
+
+impl_precommand_check_list!(A, B);
+impl_precommand_check_list!(A, B, C);
+impl_precommand_check_list!(A, B, C, D);
+
+/// Passes if every check of the tuple passes, checked in order; fails with the error of the first check that fails.
+crate struct All<T>(crate T);
+
+impl<T: PrecommandCheckList> PrecommandCheck for All<T> {
+    const HIDES_FROM_HELP: bool = T::HIDES_FROM_HELP;
+
+    fn execute_check<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters)
+        -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(all(ctx, params, T::checks()))
+    }
+}
+
+async fn all(ctx: CommandContext<'_>, params: PrecommandCheckParameters, checks: Vec<PrecommandCheckFunction>)
+    -> SystemResult<()> {
+    for check in checks {
+        check(ctx.clone(), params.clone()).await?;
+    }
+
+    Ok(())
+}
+
+/// Passes if any check of the tuple passes, checked in order. Otherwise, fails with the reasons every check denied the
+/// command for, or with the error of the first check that failed for another reason, such as a database failure.
+crate struct Any<T>(crate T);
+
+impl<T: PrecommandCheckList> PrecommandCheck for Any<T> {
+    const HIDES_FROM_HELP: bool = T::HIDES_FROM_HELP;
+
+    fn execute_check<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters)
+        -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(any(ctx, params, T::checks()))
+    }
+}
+
+async fn any(ctx: CommandContext<'_>, params: PrecommandCheckParameters, checks: Vec<PrecommandCheckFunction>)
+    -> SystemResult<()> {
+    let mut denials = Vec::new();
+    let mut failure = None;
+
+    for check in checks {
+        match check(ctx.clone(), params.clone()).await {
+            Ok(()) => return Ok(()),
+            Err(error) => match error.downcast_ref::<CommandError>() {
+                Some(CommandError::Permission(denial)) => denials.push(denial.clone()),
+                _ => {
+                    failure.get_or_insert(error);
+                }
+            }
+        }
+    }
+
+    match failure {
+        Some(error) => Err(error),
+        None => Err(box CommandError::Permission(
+            format!("None of the alternative checks passed: {}", denials.join(" "))))
+    }
+}
@@ -0,0 +1,225 @@
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    },
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant
+    }
+};
+
+use compound_duration::format_dhms;
+
+use dashmap::DashMap;
+
+use twilight_model::{
+    id::GuildId
+};
+
+use crate::{
+    command_system::{
+        CommandContext,
+        GuildConfigCache,
+        PrecommandCheckParameters
+    },
+    system::{
+        SystemResult
+    },
+    xml_deserialization::{
+        plugin_management::CommandCooldown
+    }
+};
+
+use super::PrecommandCheck;
+
+/// The scope in which invocations of a command count towards the same cooldown.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+crate enum CooldownBucket {
+    User,
+    Channel,
+    Guild
+}
+
+/// The limit of a cooldown: at most `burst` invocations per bucket within `window`.
+#[derive(Debug, Copy, Clone)]
+struct CooldownSettings {
+    bucket: CooldownBucket,
+    window: Duration,
+    burst: u32
+}
+
+impl From<&CommandCooldown> for CooldownSettings {
+    fn from(cooldown: &CommandCooldown) -> Self {
+        Self {
+            bucket: cooldown.bucket,
+            window: Duration::from_secs(cooldown.window_seconds),
+            burst: cooldown.burst
+        }
+    }
+}
+
+/// The cooldown of a command, along with the recent invocations of each of its buckets.
+///
+/// Guilds may override the default limit with a `CommandCooldown` in the `Plugins` configuration, which also limits
+/// commands that have no default limit.
+#[derive(Clone)]
+crate struct Cooldown {
+    settings: Option<CooldownSettings>,
+    invocations: Arc<DashMap<(CooldownBucket, u64), Vec<Instant>>>
+}
+
+impl Cooldown {
+    crate fn new(bucket: CooldownBucket, window: Duration, burst: u32) -> Self {
+        Self {
+            settings: Some(CooldownSettings {
+                bucket,
+                window,
+                burst
+            }),
+            invocations: Arc::new(DashMap::new())
+        }
+    }
+
+    /// A cooldown without a default limit, which only limits the command in the guilds that configure one.
+    crate fn unlimited() -> Self {
+        Self {
+            settings: None,
+            invocations: Arc::new(DashMap::new())
+        }
+    }
+
+    /// Resolves the limit for a guild, if the command is limited there; the default limit is used when the
+    /// configuration of the guild cannot be read.
+    async fn settings(&self, guild_configs: &GuildConfigCache, guild_id: Option<GuildId>, command: Option<String>)
+        -> Option<CooldownSettings> {
+        let (guild_id, command) = match (guild_id, command) {
+            (Some(guild_id), Some(command)) => (guild_id, command),
+            _ => return self.settings
+        };
+
+        match guild_configs.config(guild_id).await {
+            Ok(config) => config.plugins.command_cooldowns.cooldowns.iter()
+                .find(|cooldown| cooldown.command == command)
+                .map(CooldownSettings::from)
+                .or(self.settings),
+            Err(_) => self.settings
+        }
+    }
+}
+
+impl PrecommandCheck for Cooldown {
+    fn execute_check<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters)
+        -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(cooldown(ctx, params))
+    }
+}
+
+async fn cooldown(ctx: CommandContext<'_>, params: PrecommandCheckParameters) -> SystemResult<()> {
+    let cooldown = match params.cooldown {
+        Some(cooldown) => cooldown,
+        None => return Ok(())
+    };
+
+    let settings = match cooldown.settings(&ctx.services.guild_configs, params.guild_id, params.command_name).await {
+        Some(settings) => settings,
+        None => return Ok(())
+    };
+
+    let id = match settings.bucket {
+        CooldownBucket::User => ctx.author.id.0,
+        CooldownBucket::Channel => ctx.message.channel_id.0,
+        CooldownBucket::Guild => ctx.message.guild_id.map_or(ctx.message.channel_id.0, |guild_id| guild_id.0)
+    };
+
+    let now = Instant::now();
+    let mut invocations = cooldown.invocations.entry((settings.bucket, id)).or_default();
+
+    invocations.retain(|invoked| now.duration_since(*invoked) < settings.window);
+
+    if invocations.len() >= settings.burst as usize && let Some(oldest) = invocations.first() {
+        let remaining = settings.window - now.duration_since(*oldest);
+
+        return Err(box CooldownError { remaining });
+    }
+
+    invocations.push(now);
+
+    Ok(())
+}
+
+/// An error raised when a command is invoked again before its cooldown has expired.
+#[derive(Debug, Clone)]
+crate struct CooldownError {
+    crate remaining: Duration
+}
+
+impl Display for CooldownError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "This command is on cooldown; please wait `{}` before using it again.",
+               format_dhms(self.remaining.as_secs().max(1)))
+    }
+}
+
+impl Error for CooldownError {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        time::Duration
+    };
+
+    use crate::command_system::GuildConfigCache;
+
+    use crate::system::{
+        discord_api::fixtures::{
+            config,
+            GUILD_ID
+        },
+        stores::InMemoryConfigStore
+    };
+
+    use super::{
+        Cooldown,
+        CooldownBucket
+    };
+
+    fn guild_configs() -> GuildConfigCache {
+        let config = config("").replace("</Plugins>",
+            "<CommandCooldowns><CommandCooldown><Command>ping</Command><Bucket>Channel</Bucket>\
+             <WindowSeconds>30</WindowSeconds><Burst>1</Burst></CommandCooldown></CommandCooldowns></Plugins>");
+
+        GuildConfigCache::new(Arc::new(InMemoryConfigStore::new().config(GUILD_ID, &config)))
+    }
+
+    #[tokio::test]
+    async fn limits_commands_without_a_default_cooldown_where_configured() {
+        let guild_configs = guild_configs();
+        let cooldown = Cooldown::unlimited();
+
+        let settings = cooldown.settings(&guild_configs, Some(GUILD_ID), Some(String::from("ping"))).await.unwrap();
+
+        assert_eq!(settings.bucket, CooldownBucket::Channel);
+        assert_eq!(settings.window, Duration::from_secs(30));
+        assert_eq!(settings.burst, 1);
+
+        assert!(cooldown.settings(&guild_configs, Some(GUILD_ID), Some(String::from("pong"))).await.is_none());
+        assert!(cooldown.settings(&guild_configs, None, Some(String::from("ping"))).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn keeps_the_default_cooldown_where_not_configured() {
+        let cooldown = Cooldown::new(CooldownBucket::User, Duration::from_secs(10), 2);
+
+        let settings = cooldown.settings(&guild_configs(), Some(GUILD_ID), Some(String::from("pong"))).await.unwrap();
+
+        assert_eq!(settings.bucket, CooldownBucket::User);
+        assert_eq!(settings.burst, 2);
+    }
+}
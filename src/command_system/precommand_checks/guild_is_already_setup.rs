@@ -16,9 +16,6 @@ use crate::{
         PrecommandCheckParameters
     },
     system::{
-        twilight_http_client_extensions::{
-            GetGuildConfiguration
-        },
         SystemResult
     }
 };
@@ -35,10 +32,10 @@ impl PrecommandCheck for GuildIsAlreadySetup {
 }
 
 async fn guild_is_already_setup(ctx: CommandContext<'asynchronous_trait>, guild_id: GuildId) -> SystemResult<()> {
-    return if ctx.http_client.clone().get_guild_configuration(guild_id).await.is_ok() {
+    return if ctx.services.configs.guild_configuration(guild_id).await.is_ok() {
         Ok(())
     }
     else {
-        Err(box CommandError("Guild is not yet setup.".to_string()))
+        Err(box CommandError::User("Guild is not yet setup.".to_string()))
     }
 }
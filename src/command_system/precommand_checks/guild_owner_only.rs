@@ -34,10 +34,10 @@ async fn guild_owner_only(ctx: CommandContext<'asynchronous_trait>, params: Prec
             Ok(())
         }
         else {
-            Err(box CommandError("Command executor is not the guild owner.".to_string()))
+            Err(box CommandError::Permission("Only the owner of this server can use this command.".to_string()))
         }
     }
     else {
-        Err(box CommandError("Both the Guild ID and in-memory cache cannot be None.".to_string()))
+        Err(box CommandError::Internal("Both the Guild ID and in-memory cache cannot be None.".to_string()))
     }
 }
@@ -37,9 +37,9 @@ async fn guild_text_channel_only(ctx: CommandContext<'_>) -> SystemResult<()> {
         Channel::Guild(guild_channel) => {
             match guild_channel {
                 GuildChannel::Text(_) => Ok(()),
-                _ => Err(box CommandError("Not a guild text channel.".to_string()))
+                _ => Err(box CommandError::User("Not a guild text channel.".to_string()))
             }
         },
-        _ => Err(box CommandError("Not a guild channel".to_string()))
+        _ => Err(box CommandError::User("Not a guild channel".to_string()))
     }
 }
@@ -15,13 +15,7 @@ use crate::{
         PrecommandCheckParameters
     },
     system::{
-        twilight_http_client_extensions::{
-            GetGuildConfiguration
-        },
         SystemResult
-    },
-    xml_deserialization::{
-        BotConfig
     }
 };
 
@@ -30,6 +24,8 @@ use super::PrecommandCheck;
 crate struct HasRolePermissions;
 
 impl PrecommandCheck for HasRolePermissions {
+    const HIDES_FROM_HELP: bool = true;
+
     fn execute_check<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(has_role_permissions(ctx, params))
@@ -39,51 +35,49 @@ impl PrecommandCheck for HasRolePermissions {
 async fn has_role_permissions(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters)
     -> SystemResult<()> {
     if let Some(gid) = ctx.message.guild_id {
-        let config_str = ctx.http_client.clone().get_guild_configuration(gid).await?;
-
-        let config = quick_xml::de::from_str::<BotConfig>(&config_str)?;
+        let config = ctx.services.guild_configs.config(gid).await?;
 
         if let Some(cache) = params.cache {
-            let mut roles = ctx.http_client
-                .clone()
-                .guild_member(gid, ctx.author.id)
-                .await?
-                .unwrap()
-                .roles
-                .iter()
-                .map(|&role| {
-                    cache.role(role).unwrap()
-                })
-                .collect::<Vec<Arc<Role>>>();
+            // The check also decides what `help` lists, so a member or role that cannot be looked up denies the
+            // permission rather than failing.
+            let member = match ctx.http_client.clone().guild_member(gid, ctx.author.id).await {
+                Ok(Some(member)) => member,
+                _ => return Err(box CommandError::Permission("Your roles in this guild could not be looked up.".to_string()))
+            };
+
+            let mut roles = match member.roles.iter().map(|&role| cache.role(role)).collect::<Option<Vec<Arc<Role>>>>() {
+                Some(roles) => roles,
+                None => return Err(box CommandError::Permission("Your roles in this guild could not be looked up.".to_string()))
+            };
 
             roles.sort_by(|previous, now| {
                 now.position.cmp(&previous.position)
             });
 
-            if config.role_permission_levels.contains_key(&roles.first().unwrap().id.0) {
-                if let Some(minimum_permission_level) = params.minimum_permission_level {
-                    let set_level = config.role_permission_levels.get(&roles.first().unwrap().id.0).unwrap();
+            let set_level = roles.first().and_then(|role| config.role_permission_levels.get(&role.id.0));
 
+            if let Some(set_level) = set_level {
+                if let Some(minimum_permission_level) = params.minimum_permission_level {
                     if minimum_permission_level <= *set_level {
                         Ok(())
                     }
                     else {
-                        Err(box CommandError("The user does not have the minimum required permission to execute this command.".to_string()))
+                        Err(box CommandError::Permission("You do not have the permission level required to use this command.".to_string()))
                     }
                 }
                 else {
-                    Err(box CommandError("Minimum permission level required cannot be none, as required by the check.".to_string()))
+                    Err(box CommandError::Internal("Minimum permission level required cannot be none, as required by the check.".to_string()))
                 }
             }
             else {
-                Err(box CommandError("The user does not have the minimum required permission to execute this command".to_string()))
+                Err(box CommandError::Permission("You do not have the permission level required to use this command.".to_string()))
             }
         }
         else {
-            Err(box CommandError("Cache cannot be none, as required by the check.".to_string()))
+            Err(box CommandError::Internal("Cache cannot be none, as required by the check.".to_string()))
         }
     }
     else {
-        Err(box CommandError("Guild ID cannot be none.".to_string()))
+        Err(box CommandError::Internal("Guild ID cannot be none.".to_string()))
     }
 }
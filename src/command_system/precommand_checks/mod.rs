@@ -4,6 +4,8 @@ use std::{
 };
 
 mod bot_owner_only;
+mod combinators;
+mod cooldown;
 mod guild_is_already_setup;
 mod guild_text_channel_only;
 mod guild_owner_only;
@@ -11,6 +13,15 @@ mod has_role_permissions;
 mod support_guild_only;
 
 crate use bot_owner_only::BotOwnerOnly;
+crate use combinators::{
+    All,
+    Any
+};
+crate use cooldown::{
+    Cooldown,
+    CooldownBucket,
+    CooldownError
+};
 crate use guild_is_already_setup::GuildIsAlreadySetup;
 crate use guild_text_channel_only::GuildTextChannelOnly;
 crate use guild_owner_only::GuildOwnerOnly;
@@ -28,6 +39,9 @@ crate use crate::{
 };
 
 crate trait PrecommandCheck {
+    /// Whether `help` hides the commands a user fails this check for, as they cannot run them.
+    const HIDES_FROM_HELP: bool = false;
+
     fn execute_check<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>>;
 }
@@ -34,7 +34,7 @@ async fn support_guild_only(ctx: CommandContext<'asynchronous_trait>) -> SystemR
         Ok(())
     }
     else {
-        Err(box CommandError("The command can only be executed in the support guild.".to_string()))
+        Err(box CommandError::Permission("This command can only be used in the support server.".to_string()))
     }
 }
 
@@ -0,0 +1,78 @@
+use std::{
+    sync::Arc,
+    time::{
+        Duration,
+        Instant
+    }
+};
+
+use dashmap::DashMap;
+
+use twilight_model::{
+    id::MessageId
+};
+
+/// How long after a command was invoked that editing the invoking message runs it again.
+const RERUN_WINDOW: Duration = Duration::from_secs(120);
+
+/// A message that invoked a command, along with the replies of the bot to it.
+struct Invocation {
+    invoked_at: Instant,
+    succeeded: bool,
+    responses: Vec<MessageId>
+}
+
+/// Maps the messages that recently invoked commands to the replies of the bot, so that editing an invoking message
+/// can run the command again in place of the earlier replies.
+#[derive(Clone, Default)]
+crate struct ResponseTracker {
+    invocations: Arc<DashMap<MessageId, Invocation>>
+}
+
+impl ResponseTracker {
+    crate fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records that a message invoked a command; this is done before the command is executed, so that the replies it
+    /// sends are tracked as well.
+    crate fn track(&self, message_id: MessageId) {
+        self.invocations.retain(|_, invocation| invocation.invoked_at.elapsed() < RERUN_WINDOW);
+
+        let mut invocation = self.invocations.entry(message_id).or_insert_with(|| Invocation {
+            invoked_at: Instant::now(),
+            succeeded: false,
+            responses: Vec::new()
+        });
+
+        invocation.succeeded = false;
+    }
+
+    /// Records whether the command a message invoked was executed successfully.
+    crate fn complete(&self, message_id: MessageId, succeeded: bool) {
+        if let Some(mut invocation) = self.invocations.get_mut(&message_id) {
+            invocation.succeeded = succeeded;
+        }
+    }
+
+    /// Records a reply of the bot to a message, if that message invoked a command.
+    crate fn add_response(&self, message_id: MessageId, response_id: MessageId) {
+        if let Some(mut invocation) = self.invocations.get_mut(&message_id) {
+            invocation.responses.push(response_id);
+        }
+    }
+
+    /// Whether an edit of a message should run its command again; that is, whether the command it invoked within the
+    /// window produced no result or failed.
+    ///
+    /// If so, the earlier replies are returned to be deleted, and are no longer tracked.
+    crate fn rerun(&self, message_id: MessageId) -> Option<Vec<MessageId>> {
+        let mut invocation = self.invocations.get_mut(&message_id)?;
+
+        if invocation.succeeded || invocation.invoked_at.elapsed() >= RERUN_WINDOW {
+            return None;
+        }
+
+        Some(invocation.responses.drain(..).collect())
+    }
+}
@@ -20,8 +20,8 @@ extern crate sha3;
 use std::{
     env::*,
     error::Error,
-    pin::Pin,
-    sync::Arc
+    sync::Arc,
+    time::Duration
 };
 
 use dotenv::dotenv;
@@ -31,11 +31,6 @@ use futures_util::{
     stream::StreamExt
 };
 
-use sha3::{
-    Digest,
-    Sha3_512
-};
-
 use twilight_cache_inmemory::{
     ResourceType,
     InMemoryCache
@@ -75,6 +70,8 @@ use twilight_model::{
     }
 };
 
+use twilight_standby::Standby;
+
 crate mod command_system;
 crate mod content_distribution_network;
 crate mod logging;
@@ -90,35 +87,45 @@ use crate::command_system::{
         emitter::CommandEventEmitter,
         events::SystemEvent
     },
+    hooks::{
+        ErrorReply,
+        TypingIndicator
+    },
     parser::{
-        Command,
+        CommandParseError,
         CommandParser
     },
     precommand_checks::{
+        All,
+        Any,
         BotOwnerOnly,
+        CooldownBucket,
         GuildIsAlreadySetup,
         GuildTextChannelOnly,
         GuildOwnerOnly,
         HasRolePermissions,
-        PrecommandCheck,
         SupportGuildOnly
     },
-    Command as CommandTrait,
+    CommandChecks,
     CommandContext,
     CommandContextRef,
     CommandFramework,
-    PrecommandCheckParameters,
-    PrecommandCheckParametersBuilder
+    CommandRegistry,
+    CommandServices,
+    ResponseTracker
 };
 
 use crate::system::{
     bot_configuration::BotConfiguration,
+    database::{
+        DatabaseConfiguration,
+        DatabasePools
+    },
     event_handler::EventHandler,
     internal_bot_error::report_ibe,
+    migrations::migrate_databases,
     model::{
         payload::{
-            CommandExecuted,
-            CommandFailed,
             CommandReceived
         }
     },
@@ -235,6 +242,7 @@ use crate::plugins::{
         SelfmuteCommand,
     },
     owneronly::{
+        ErrorCommand,
         RefreshWhitelistRolesCommand,
         RestartCommand,
         StopCommand,
@@ -251,6 +259,10 @@ use crate::plugins::{
     }
 };
 
+use crate::utilities::{
+    constants::default_command_prefix
+};
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // Creates a new stopwatch.
@@ -275,6 +287,30 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // Creates a new configuration object.
     let bot_configuration = BotConfiguration::new(hartex_token);
 
+    Logger::log_debug("Connecting to the databases.");
+
+    let database_configuration = DatabaseConfiguration::from_environment()?;
+    let database_pools = DatabasePools::connect(database_configuration).await?;
+
+    // Commands cannot do much without the databases, so the bot does not start without them.
+    if let Err(error) = database_pools.health_check().await {
+        Logger::log_error(format!("Database health check failed. Error: {}", error));
+
+        return Err(error);
+    }
+
+    Logger::log_debug("Applying database migrations.");
+
+    // The bot cannot keep its data in databases it does not know the schema of.
+    match migrate_databases(&database_pools).await {
+        Ok(count) => Logger::log_debug(format!("Applied {} database migrations.", count)),
+        Err(error) => {
+            Logger::log_error(format!("Could not apply database migrations. Error: {}", error));
+
+            return Err(error);
+        }
+    }
+
     // Sharding scheme
     let shard_scheme = ShardScheme::Auto;
 
@@ -325,117 +361,206 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     // HarTex command framework
     Logger::log_debug("Initializing command framework.");
-    let framework = CommandFramework::new();
-
-    // Initialization of command parser.
-    let command_parser = {
+    let framework = {
         Logger::log_debug("Registering commands.");
 
-        framework
-            .clone()
-            .command_prefix("hb.")
+        CommandFramework::new()
+            .command_prefix(default_command_prefix())
+            .hook(TypingIndicator)
+            .hook(ErrorReply)
 
             // Administrator Command Module
-            .command(CleanAllCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(RoleAddCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(RoleRemoveCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(RoleGlobalAddCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(RoleGlobalRemoveCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(RoleinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(CleanUserCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(CleanBotsCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(LockdownChannelCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(UnlockdownChannelCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(SlowmodeEnableHereCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(SlowmodeDisableHereCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(SlowmodeEnableChannelCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(SlowmodeDisableChannelCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(VoicemuteEnableCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(VoicemuteDisableCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(NorolesListCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(NorolesKickCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(NicknameChangeCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(NicknameRemoveCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(WebconfigListCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(InvitesCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(LockdownGuildCommand, CaseSensitive, NoFullyQualifiedName, DisableAliases)
-            .command(UnlockdownGuildCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(CleanAllCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(RoleAddCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(RoleRemoveCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(RoleGlobalAddCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(RoleGlobalRemoveCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(RoleinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(CleanUserCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(CleanBotsCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(LockdownChannelCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(UnlockdownChannelCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(SlowmodeEnableHereCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(SlowmodeDisableHereCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(SlowmodeEnableChannelCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(SlowmodeDisableChannelCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(VoicemuteEnableCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(VoicemuteDisableCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NorolesListCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NorolesKickCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NicknameChangeCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NicknameRemoveCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(WebconfigListCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(InvitesCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(LockdownGuildCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
+            .command(UnlockdownGuildCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions).check(GuildTextChannelOnly))
 
             // General Command Module
-            .command(PingCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(HelpCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(AboutCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(TeamCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(UptimeCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(PingCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
+            .command(HelpCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
+            .command(AboutCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
+            .command(TeamCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new())
+            .command(UptimeCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
 
             // Guild Owneronly Command Module
-            .command(SetupCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(SetupCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(GuildOwnerOnly).check(GuildIsAlreadySetup))
 
             // Information Command Module
-            .command(UserinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(GuildinfoCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(BotinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(UserinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().cooldown(CooldownBucket::User, Duration::from_secs(10), 2))
+            .command(GuildinfoCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().cooldown(CooldownBucket::User, Duration::from_secs(10), 2))
+            .command(BotinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().cooldown(CooldownBucket::User, Duration::from_secs(10), 2))
 
             // Infractions Command Module
-            .command(InfractionSearchCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(InfractionRemoveCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(InfractionsArchiveCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(InfractionClearallCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(InfractionReasonCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-
-            .command(DmWarnCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmUnmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmBanCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmKickCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMkickCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmCleanBanCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmUnbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmTempmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMwarnCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmTempbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMunbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-            .command(DmMunmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases)
-
-            .command(NodmWarnCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmUnmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmBanCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmKickCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMkickCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmCleanBanCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmUnbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmTempmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMwarnCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmTempbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMunbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(NodmMunmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-
-            .command(SelfmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(InfractionSearchCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions)
+                         .cooldown(CooldownBucket::User, Duration::from_secs(10), 2))
+            .command(InfractionRemoveCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(InfractionsArchiveCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions)
+                         .cooldown(CooldownBucket::Guild, Duration::from_secs(60), 1))
+            .command(InfractionClearallCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(Any((GuildOwnerOnly, HasRolePermissions))))
+            .command(InfractionReasonCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+
+            .command(DmWarnCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmUnmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmBanCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmKickCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMkickCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmCleanBanCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmUnbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmTempmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMwarnCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmTempbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMunbanCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(DmMunmuteCommand, CaseSensitive, FullyQualifiedName, EnableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+
+            .command(NodmWarnCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmUnmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmBanCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmKickCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMkickCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmCleanBanCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmUnbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmTempmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMwarnCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmTempbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMunbanCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+            .command(NodmMunmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(HasRolePermissions))
+
+            .command(SelfmuteCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
 
             // Owneronly Command Module
-            .command(RefreshWhitelistRolesCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(RestartCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(StopCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(SupportinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(SupportAnnounceCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(RefreshWhitelistRolesCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(All((BotOwnerOnly, SupportGuildOnly))))
+            .command(ErrorCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(BotOwnerOnly))
+            .command(RestartCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(BotOwnerOnly))
+            .command(StopCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(BotOwnerOnly))
+            .command(SupportinfoCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(All((BotOwnerOnly, SupportGuildOnly))))
+            .command(SupportAnnounceCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(All((BotOwnerOnly, SupportGuildOnly))))
 
             // Utilities Command Module
-            .command(CoinflipCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(EmojiCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-            .command(RandintCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
+            .command(CoinflipCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
+            .command(EmojiCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
+            .command(RandintCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new())
 
             // Whitelist Command Module
-            .command(AcceptCommand, CaseSensitive, FullyQualifiedName, DisableAliases)
-
-            // Builds the parser with the configured commands and case sensitivity
-            .build_parser()
+            .command(AcceptCommand, CaseSensitive, FullyQualifiedName, DisableAliases,
+                     CommandChecks::new().check(BotOwnerOnly))
     };
 
+    // Builds the parser with the configured commands and case sensitivity
+    let command_parser = framework.clone().build_parser();
+
+    // Builds the registry of command handlers used to dispatch the parsed commands
+    let command_registry = framework.clone().build_registry();
+
+    // Maps the messages that recently invoked commands to the replies of the bot, for re-running edited commands
+    let response_tracker = ResponseTracker::new();
+
+    // Lets commands wait for the reactions to their confirmation prompts
+    let hartex_standby = Standby::new();
+
     let resource_types =
         ResourceType::CHANNEL |
         ResourceType::EMOJI |
@@ -470,6 +595,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         EventTypeFlags::MESSAGE_DELETE |
         EventTypeFlags::MESSAGE_DELETE_BULK |
         EventTypeFlags::MESSAGE_UPDATE |
+        EventTypeFlags::REACTION_ADD |
         EventTypeFlags::READY |
         EventTypeFlags::SHARD_CONNECTED |
         EventTypeFlags::SHARD_CONNECTING |
@@ -490,6 +616,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     // Framework Listeners
     let listeners = framework.clone().listeners();
     let emitter = CommandEventEmitter::new(listeners);
+    let services = CommandServices::new(hartex_http.clone(), database_pools, emitter.clone());
+
+    // Temporary mutes and bans that were pending when the bot stopped are picked up again.
+    match services.scheduler.load().await {
+        Ok(count) => Logger::log_debug(format!("Loaded {} scheduled actions.", count)),
+        Err(error) => Logger::log_error(format!("Could not load scheduled actions. Error: {}", error))
+    }
 
     // Cluster events
     let mut events = hartex_cluster.some_events(event_types);
@@ -508,6 +641,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
         match value {
             Either::Left(event) => {
                 hartex_cache.update(&event.0.clone().unwrap().1);
+                hartex_standby.process(&event.0.clone().unwrap().1);
 
                 tokio::spawn(
                     handle_event(
@@ -518,9 +652,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
                         hartex_http.clone(),
                         hartex_cluster.clone(),
                         command_parser.clone(),
+                        command_registry.clone(),
+                        response_tracker.clone(),
+                        hartex_standby.clone(),
                         hartex_cache.clone(),
                         stopwatch,
-                        emitter.clone()
+                        emitter.clone(),
+                        services.clone()
                     )
                 );
             },
@@ -534,9 +672,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
                         hartex_http.clone(),
                         hartex_cluster.clone(),
                         command_parser.clone(),
+                        command_registry.clone(),
+                        response_tracker.clone(),
+                        hartex_standby.clone(),
                         hartex_cache.clone(),
                         stopwatch,
-                        emitter.clone()
+                        emitter.clone(),
+                        services.clone()
                     )
                 );
             }
@@ -553,9 +695,13 @@ async fn handle_event(_shard_id: Option<u64>,
                       http_client: TwilightHttpClient,
                       cluster: Cluster,
                       parser: CommandParser<'static>,
+                      registry: CommandRegistry,
+                      responses: ResponseTracker,
+                      standby: Standby,
                       cache: InMemoryCache,
                       stopwatch: Stopwatch,
-                      emitter: CommandEventEmitter)
+                      emitter: CommandEventEmitter,
+                      services: CommandServices)
     -> Result<(), Box<dyn Error + Send + Sync>> {
     match event_type {
         EventType::TwilightEvent => {
@@ -580,49 +726,35 @@ async fn handle_event(_shard_id: Option<u64>,
                         EventHandler::ready(ready, stopwatch).await
                     },
                     Event::MessageCreate(message_create) => {
+                        let current_user_id = cache.current_user().map(|current_user| current_user.id);
+
+                        // Replies of the bot are tracked, so that they can be replaced when the invoking message is
+                        // edited.
+                        if Some(message_create.author.id) == current_user_id
+                            && let Some(reference) = &message_create.reference
+                            && let Some(message_id) = reference.message_id {
+                            responses.add_response(message_id, message_create.id);
+                        }
+
                         if (*message_create).author.bot {
                             return Ok(());
                         }
 
-                        if message_create.content.starts_with("hb.") {
-                            emitter.event(SystemEvent::CommandReceived(box CommandReceived));
-
-                            match handle_command(
-                                (*message_create).clone().0,
-                                CommandContext(
-                                    Arc::new(
-                                        CommandContextRef::new(
-                                            http_client.clone(),
-                                            parser,
-                                            cluster,
-                                            (*message_create).clone().0,
-                                            stopwatch
-                                        )
-                                    ),
-                                ),
-                                http_client.clone(),
-                                cache,
-                                emitter
-                            ).await {
-                                Ok(()) => (),
-                                Err(error) => {
-                                    Logger::log_error(format!("Failed to handle message: {}", error));
-
-                                    let error_hash =
-                                        format!("{:x}",
-                                                Sha3_512::digest(format!("{}{:?}{:?}",
-                                                                         error.to_string(),
-                                                                         message_create.guild_id,
-                                                                         message_create.id).as_str().as_bytes()));
-                                    let message =
-                                        format!(
-                                            "Oops! This command raised an error. Please join or go to our Support Server (you need to get the **_HarTex** role, go to <#667597397215674368>) and provide the error code below for further troubleshooting and investigation.\n\nServer Invite: discord.gg/s8qjxZK\n\nError code: `{}`", error_hash);
-
-                                    http_client.clone().create_message(message_create.channel_id).content(message)?.await?;
-                                }
-                            }
-                        }
-                        else if message_create.content.to_lowercase().contains("harry") {
+                        let handled = handle_message(
+                            (*message_create).clone().0,
+                            http_client.clone(),
+                            cluster,
+                            parser,
+                            registry,
+                            responses,
+                            standby,
+                            cache,
+                            stopwatch,
+                            emitter,
+                            services
+                        ).await?;
+
+                        if !handled && message_create.content.to_lowercase().contains("harry") {
                             http_client
                                 .clone()
                                 .create_reaction(message_create.channel_id,
@@ -631,12 +763,42 @@ async fn handle_event(_shard_id: Option<u64>,
                                         name: None
                                     }).await?;
                         }
-                        else {}
+
+                        Ok(())
+                    },
+                    Event::MessageUpdate(message_update) => {
+                        // Only edits of the content can change the command a message invokes.
+                        if message_update.content.is_none() {
+                            return Ok(());
+                        }
+
+                        if let Some(earlier_responses) = responses.rerun(message_update.id) {
+                            for response in earlier_responses {
+                                // The reply may have been deleted already, leaving nothing to replace.
+                                let _ = http_client.delete_message(message_update.channel_id, response).await;
+                            }
+
+                            if let Some(message) = http_client.message(message_update.channel_id, message_update.id).await? {
+                                handle_message(
+                                    message,
+                                    http_client.clone(),
+                                    cluster,
+                                    parser,
+                                    registry,
+                                    responses,
+                                    standby,
+                                    cache,
+                                    stopwatch,
+                                    emitter,
+                                    services
+                                ).await?;
+                            }
+                        }
 
                         Ok(())
                     },
                     Event::GuildCreate(guild_create) => {
-                        EventHandler::guild_create(guild_create, http_client).await
+                        EventHandler::guild_create(guild_create, http_client, &*services.whitelist).await
                     },
                     _ => Ok(())
                 }
@@ -659,6 +821,36 @@ async fn handle_event(_shard_id: Option<u64>,
                     },
                     SystemEvent::CommandIdentified(command_identified) => {
                         EventHandler::command_identified(command_identified).await
+                    },
+                    SystemEvent::InfractionCreated(infraction_created) => {
+                        EventHandler::infraction_created(infraction_created).await
+                    },
+                    SystemEvent::InfractionRemoved(infraction_removed) => {
+                        EventHandler::infraction_removed(infraction_removed).await
+                    },
+                    SystemEvent::InfractionUpdated(infraction_updated) => {
+                        EventHandler::infraction_updated(infraction_updated).await
+                    },
+                    SystemEvent::InfractionsCleared(infractions_cleared) => {
+                        EventHandler::infractions_cleared(infractions_cleared).await
+                    },
+                    SystemEvent::MemberMuted(member_muted) => {
+                        EventHandler::member_muted(member_muted).await
+                    },
+                    SystemEvent::MemberUnmuted(member_unmuted) => {
+                        EventHandler::member_unmuted(member_unmuted).await
+                    },
+                    SystemEvent::LockdownStarted(lockdown_started) => {
+                        EventHandler::lockdown_started(lockdown_started).await
+                    },
+                    SystemEvent::LockdownEnded(lockdown_ended) => {
+                        EventHandler::lockdown_ended(lockdown_ended).await
+                    },
+                    SystemEvent::GuildWhitelisted(guild_whitelisted) => {
+                        EventHandler::guild_whitelisted(guild_whitelisted).await
+                    },
+                    SystemEvent::ConfigChanged(config_changed) => {
+                        EventHandler::config_changed(config_changed, &services.guild_configs).await
                     }
                 }
             }
@@ -671,2930 +863,126 @@ async fn handle_event(_shard_id: Option<u64>,
     Ok(())
 }
 
-async fn handle_command(message: Message,
-                        context: CommandContext<'static>,
+/// Handles a message that may invoke a command, returning whether it started with a prefix.
+async fn handle_message(message: Message,
                         http_client: TwilightHttpClient,
+                        cluster: Cluster,
+                        parser: CommandParser<'static>,
+                        registry: CommandRegistry,
+                        responses: ResponseTracker,
+                        standby: Standby,
                         cache: InMemoryCache,
-                        emitter: CommandEventEmitter) -> Result<(), Box<dyn Error + Send + Sync>> {
-    if let Some(command) = context.command_parser.parse(&message.content) {
-        emitter.event(SystemEvent::CommandIdentified(command.name.to_string()));
-
-        match command {
-            // Administrator Command Module
-            Command { name: "clean", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("all") => {
-                        match CleanAllCommand::precommand_check(context.clone(),
-                                                               PrecommandCheckParametersBuilder::new()
-                                                                   .in_memory_cache(cache.clone())
-                                                                   .minimum_permission_level(60).build(),
-                                                               |ctx, params|
-                                                                   Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match CleanAllCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "clean all",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "clean all",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "clean all",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("user") => {
-                        match CleanUserCommand::precommand_check(context.clone(), 
-                                                                PrecommandCheckParametersBuilder::new()
-                                                                    .in_memory_cache(cache.clone())
-                                                                    .minimum_permission_level(60).build(),
-                                                                |ctx, params| 
-                                                                    Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match CleanUserCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "clean user",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "clean user",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "clean user",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("bots") => {
-                        match CleanBotsCommand::precommand_check(context.clone(),
-                                                                 PrecommandCheckParametersBuilder::new()
-                                                                     .in_memory_cache(cache.clone())
-                                                                     .minimum_permission_level(60).build(),
-                                                                 |ctx, params|
-                                                                     Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match CleanUserCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "clean bots",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "clean bots",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "clean bots",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "role", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("add") => {
-                        match RoleAddCommand::precommand_check(context.clone(),
-                                                         PrecommandCheckParametersBuilder::new()
-                                                             .in_memory_cache(cache.clone())
-                                                             .minimum_permission_level(60).build(),
-                                                         |ctx, params|
-                                                             Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match RoleAddCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "role add",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "role add",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "role add",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("remove") => {
-                        match RoleRemoveCommand::precommand_check(context.clone(),
-                                                               PrecommandCheckParametersBuilder::new()
-                                                                   .in_memory_cache(cache.clone())
-                                                                   .minimum_permission_level(60).build(),
-                                                               |ctx, params|
-                                                                   Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match RoleRemoveCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "role remove",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "role remove",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "role remove",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("global-add") => {
-                        match RoleGlobalAddCommand::precommand_check(context.clone(),
-                                                                     PrecommandCheckParametersBuilder::new()
-                                                                         .in_memory_cache(cache.clone())
-                                                                         .minimum_permission_level(80).build(),
-                                                                     |ctx, params|
-                                                                         Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match RoleGlobalAddCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "role global-add",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "role gloabl-add",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "role global-add",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("global-remove") => {
-                        match RoleGlobalRemoveCommand::precommand_check(context.clone(),
-                                                                     PrecommandCheckParametersBuilder::new()
-                                                                         .in_memory_cache(cache.clone())
-                                                                         .minimum_permission_level(80).build(),
-                                                                     |ctx, params|
-                                                                         Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                            .await {
-                            Ok(()) => {
-                                match RoleGlobalRemoveCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "role global-remove",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "role global-remove",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "role global-remove",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "role-info", arguments, .. } => {
-                match RoleinfoCommand::precommand_check(context.clone(),
-                                                        PrecommandCheckParametersBuilder::new()
-                                                            .in_memory_cache(cache.clone())
-                                                            .minimum_permission_level(80).build(),
-                                                        |ctx, params|
-                                                            Box::pin(HasRolePermissions::execute_check(ctx, params)))
-                    .await {
-                    Ok(()) => {
-                        match RoleinfoCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "role-info",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "role-info",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "role-info",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "lockdown", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("channel") => {
-                        match LockdownChannelCommand::precommand_checks(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            Box::<[
-                                    for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                    (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                |ctx, params|
-                                    HasRolePermissions::execute_check(ctx, params)
-                                , |ctx, params|
-                                    GuildTextChannelOnly::execute_check(ctx, params)
-                            ])).await {
-                            Ok(()) => {
-                                match LockdownChannelCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "lockdown channel",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "lockdown channel",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "lockdown channel",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("guild") => {
-                        match LockdownGuildCommand::precommand_checks(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            Box::<[
-                                    for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                    (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                |ctx, params|
-                                    HasRolePermissions::execute_check(ctx, params)
-                                , |ctx, params|
-                                    GuildTextChannelOnly::execute_check(ctx, params)
-                            ])).await {
-                            Ok(()) => {
-                                match LockdownGuildCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "lockdown guild",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "lockdown guild",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "lockdown guild",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "unlockdown", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("channel") => {
-                        match UnlockdownChannelCommand::precommand_checks(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            Box::<[
-                                    for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                    (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                |ctx, params|
-                                    HasRolePermissions::execute_check(ctx, params)
-                                , |ctx, params|
-                                    GuildTextChannelOnly::execute_check(ctx, params)
-                            ])).await {
-                            Ok(()) => {
-                                match UnlockdownChannelCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "unlockdown channel",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "unlockdowm channel",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "unlockdown channel",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("guild") => {
-                        match UnlockdownGuildCommand::precommand_checks(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            Box::<[
-                                    for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                    (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                |ctx, params|
-                                    HasRolePermissions::execute_check(ctx, params)
-                                , |ctx, params|
-                                    GuildTextChannelOnly::execute_check(ctx, params)
-                            ])).await {
-                            Ok(()) => {
-                                match UnlockdownGuildCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "unlockdown guild",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "unlockdown guild",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "unlockdown guild",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "slowmode", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("enable") => {
-                        let position = arguments.next();
-
-                        match position {
-                            Some("here") => {
-                                match SlowmodeEnableHereCommand::precommand_checks(
-                                    context.clone(),
-                                    PrecommandCheckParametersBuilder::new()
-                                        .in_memory_cache(cache.clone())
-                                        .minimum_permission_level(60).build(),
-                                    Box::<[
-                                            for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                        -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                            (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                        |ctx, params|
-                                            HasRolePermissions::execute_check(ctx, params)
-                                        , |ctx, params|
-                                            GuildTextChannelOnly::execute_check(ctx, params)
-                                    ])).await {
-                                    Ok(()) => {
-                                        match SlowmodeEnableHereCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                            Ok(()) => {
-                                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                                    Some(guild) => guild.name,
-                                                    None => String::new()
-                                                };
-
-                                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                                    command: "slowmode enable here",
-                                                    guild_name: guild,
-                                                    context: context.clone()
-                                                }))
-                                            },
-                                            Err(error) => {
-                                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                                    command: "slowmode enable here",
-                                                    error: format!("{}", error)
-                                                }))
-                                            }
-                                        }
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "slowmode enable here",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Some("channel") => {
-                                match SlowmodeEnableChannelCommand::precommand_checks(
-                                    context.clone(),
-                                    PrecommandCheckParametersBuilder::new()
-                                        .in_memory_cache(cache.clone())
-                                        .minimum_permission_level(60).build(),
-                                    Box::<[
-                                            for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                        -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                            (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                        |ctx, params|
-                                            HasRolePermissions::execute_check(ctx, params)
-                                        , |ctx, params|
-                                            GuildTextChannelOnly::execute_check(ctx, params)
-                                    ])).await {
-                                    Ok(()) => {
-                                        match SlowmodeEnableChannelCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                            Ok(()) => {
-                                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                                    Some(guild) => guild.name,
-                                                    None => String::new()
-                                                };
-
-                                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                                    command: "slowmode enable channel",
-                                                    guild_name: guild,
-                                                    context: context.clone()
-                                                }))
-                                            },
-                                            Err(error) => {
-                                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                                    command: "slowmode enable channel",
-                                                    error: format!("{}", error)
-                                                }))
-                                            }
-                                        }
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "slowmode enable channel",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            _ => Logger::log_error(
-                                format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                        }
-                    },
-                    Some("disable") => {
-                        let position = arguments.next();
-
-                        match position {
-                            Some("here") => {
-                                match SlowmodeDisableHereCommand::precommand_checks(
-                                    context.clone(),
-                                    PrecommandCheckParametersBuilder::new()
-                                        .in_memory_cache(cache.clone())
-                                        .minimum_permission_level(60).build(),
-                                    Box::<[
-                                            for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                        -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                            (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                        |ctx, params|
-                                            HasRolePermissions::execute_check(ctx, params)
-                                        , |ctx, params|
-                                            GuildTextChannelOnly::execute_check(ctx, params)
-                                    ])).await {
-                                    Ok(()) => {
-                                        match SlowmodeDisableHereCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                            Ok(()) => {
-                                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                                    Some(guild) => guild.name,
-                                                    None => String::new()
-                                                };
-
-                                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                                    command: "slowmode disable here",
-                                                    guild_name: guild,
-                                                    context: context.clone()
-                                                }))
-                                            },
-                                            Err(error) => {
-                                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                                    command: "slowmode disable here",
-                                                    error: format!("{}", error)
-                                                }))
-                                            }
-                                        }
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "slowmode disable here",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Some("channel") => {
-                                match SlowmodeDisableChannelCommand::precommand_checks(
-                                    context.clone(),
-                                    PrecommandCheckParametersBuilder::new()
-                                        .in_memory_cache(cache.clone())
-                                        .minimum_permission_level(60).build(),
-                                    Box::<[
-                                            for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                                                        -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                                                            (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                                        |ctx, params|
-                                            HasRolePermissions::execute_check(ctx, params)
-                                        , |ctx, params|
-                                            GuildTextChannelOnly::execute_check(ctx, params)
-                                    ])).await {
-                                    Ok(()) => {
-                                        match SlowmodeDisableChannelCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                            Ok(()) => {
-                                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                                    Some(guild) => guild.name,
-                                                    None => String::new()
-                                                };
-
-                                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                                    command: "slowmode disable channel",
-                                                    guild_name: guild,
-                                                    context: context.clone()
-                                                }))
-                                            },
-                                            Err(error) => {
-                                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                                    command: "slowmode disable channel",
-                                                    error: format!("{}", error)
-                                                }))
-                                            }
-                                        }
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "slowmode disable channel",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            _ => Logger::log_error(
-                                format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "voicemute", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("enable") => {
-                        match VoicemuteEnableCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                HasRolePermissions::execute_check(ctx, params)).await {
-                            Ok(()) => {
-                                match VoicemuteEnableCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "voicemute enable",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "voicemute enable",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "voicemut enable",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("disable") => {
-                        match VoicemuteDisableCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                HasRolePermissions::execute_check(ctx, params)).await {
-                            Ok(()) => {
-                                match VoicemuteDisableCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "voicemute disable",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "voicemute disable",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "voicemute disable",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "noroles", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("list") => {
-                        match NorolesListCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                HasRolePermissions::execute_check(ctx, params)).await {
-                            Ok(()) => {
-                                match NorolesListCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "noroles list",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "noroles list",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "noroles list",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("kick") => {
-                        match NorolesKickCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(80).build(),
-                            |ctx, params|
-                                HasRolePermissions::execute_check(ctx, params)).await {
-                            Ok(()) => {
-                                match NorolesKickCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "noroles kick",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "noroles kick",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "noroles kick",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "nickname", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("change") => {
-                        match NicknameChangeCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                HasRolePermissions::execute_check(ctx, params)).await {
-                            Ok(()) => {
-                                match NicknameChangeCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "nickname change",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "nickname change",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nickname change",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("remove") => {
-                        match NicknameRemoveCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                HasRolePermissions::execute_check(ctx, params)).await {
-                            Ok(()) => {
-                                match NicknameRemoveCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "nickname remove",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "nickname remove",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nickname remove",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "webconfig", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("list") => {
-                        match WebconfigListCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(80).build(),
-                                |ctx, params|
-                                    HasRolePermissions::execute_check(ctx, params)
-                        ).await {
-                            Ok(()) => {
-                                match WebconfigListCommand::execute_command(context.clone(), arguments, cache.clone()).await {
-                                    Ok(()) => {
-                                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                            Some(guild) => guild.name,
-                                            None => String::new()
-                                        };
-
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "webconfig list",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "webconfig list",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "webconfig list",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => Logger::log_error(
-                        format!("Command '{}' failed due to an error: 'command not found'.", message.content))
-                }
-            },
-            Command { name: "invites", .. } => {
-                match InvitesCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(80).build(),
-                        |ctx, params| 
-                            HasRolePermissions::execute_check(ctx, params)
-                ).await {
-                    Ok(()) => {
-
-                    },
-                    Err(_error) => {
-                        
-                    }
-                }
-            },
-
-            // General Command Module
-            Command { name: "ping", arguments, .. } => {
-                match PingCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "ping",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "ping",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "bot-info", arguments, .. } => {
-                match BotinfoCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "bot-info",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "bot-info",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "help", arguments, .. } => {
-                match HelpCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "help",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "help",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "about", arguments, .. } => {
-                match AboutCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "about",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "about",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "team", arguments, .. } | Command { name: "staff", arguments, .. } => {
-                match TeamCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "team",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "team",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "uptime", arguments, .. } => {
-                match UptimeCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "uptime",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "uptime",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
+                        stopwatch: Stopwatch,
+                        emitter: CommandEventEmitter,
+                        services: CommandServices) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    let customization = services.guild_configs.customization(message.guild_id).await;
+    let guild_prefix = customization.command_prefix;
+    let suggest_commands = customization.suggest_commands;
+    let current_user_id = cache.current_user().map(|current_user| current_user.id);
+
+    let prefix = match parser.find_prefix(&message.content, &guild_prefix, current_user_id) {
+        Some(prefix) => prefix.to_string(),
+        None => return Ok(false)
+    };
 
-            // Information Command Module
-            Command { name: "user-info", arguments, .. } => {
-                match UserinfoCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "user-info",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "user-info",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "guild-info", arguments, .. } | Command { name: "server-info", arguments, .. } => {
-                match GuildinfoCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "guild-info",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "guild-info",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
+    // A bare mention of the bot replies with the prefix of the guild, or the default prefix in direct messages.
+    if prefix != guild_prefix && message.content[prefix.len()..].trim().is_empty() {
+        let content = match message.guild_id {
+            Some(_) => format!("My command prefix in this server is `{}`.", guild_prefix),
+            None => format!("My command prefix is `{}`.", guild_prefix)
+        };
+
+        http_client.clone().create_message(message.channel_id)
+            .content(content)?
+            .allowed_mentions()
+            .replied_user(false)
+            .build()
+            .reply(message.id)
+            .await?;
+
+        return Ok(true);
+    }
 
-            // Guild Owneronly Command Module
-            Command { name: "setup", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match SetupCommand::precommand_checks(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .guild_id(context.clone().message.guild_id.unwrap()).build(),
-                    Box::<[
-                            for<'asynchronous_trait> fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                                -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                                    (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                        |ctx, params|
-                            GuildOwnerOnly::execute_check(ctx, params)
-                        , |ctx, params|
-                            GuildIsAlreadySetup::execute_check(ctx, params)
-                    ])).await {
-                    Ok(()) => {
-                        match SetupCommand::execute_command(context.clone(), arguments, cache)
-                            .await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "setup",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "setup",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "setup",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
+    emitter.event(SystemEvent::CommandReceived(box CommandReceived)).await;
+
+    let result = handle_command(
+        message.clone(),
+        CommandContext(
+            Arc::new(
+                CommandContextRef::new(
+                    http_client.clone(),
+                    parser,
+                    cluster,
+                    registry.clone(),
+                    standby,
+                    message.clone(),
+                    stopwatch,
+                    emitter.clone(),
+                    services
+                )
+            ),
+        ),
+        prefix,
+        if suggest_commands { Some(guild_prefix) } else { None },
+        registry,
+        responses,
+        cache,
+        emitter
+    ).await;
+
+    // Replies to failed commands are left to the hooks; an error here is in handling the message itself.
+    if let Err(error) = result {
+        Logger::log_error(format!("Failed to handle message: {}", error));
+    }
 
-            // Infractions Command Module
-            Command { name: "warn", arguments, .. }  |
-            Command { name: "dmwarn", arguments, .. } => {
-                match DmWarnCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        match DmWarnCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmwarn",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmwarn",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmwarn",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmwarn", arguments, .. } => {
-                match NodmWarnCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        match NodmWarnCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmwarn",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmwarn",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmwarn",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "inf", mut arguments, .. } => {
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("search") => {
-                        match InfractionSearchCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(80).build(),
-                            |ctx, params|
-                                Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                match InfractionSearchCommand::execute_command(context.clone(), arguments, cache).await {
-                                    Ok(()) => {
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "inf search",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "inf search",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "inf search",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("remove") => {
-                        match InfractionRemoveCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(80).build(),
-                            |ctx, params|
-                                Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                match InfractionRemoveCommand::execute_command(context.clone(), arguments, cache).await {
-                                    Ok(()) => {
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "inf remove",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "inf remove",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "inf remove",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("archive") => {
-                        match InfractionsArchiveCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(80).build(),
-                            |ctx, params|
-                                Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                match InfractionsArchiveCommand::execute_command(context.clone(), arguments, cache).await {
-                                    Ok(()) => {
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "inf archive",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "inf archive",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "inf archive",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("clear-all") => {
-                        match InfractionClearallCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                match InfractionClearallCommand::execute_command(context.clone(), arguments, cache).await {
-                                    Ok(()) => {
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "inf clear-all",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "inf clear-all",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "inf clear-all",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Some("reason") => {
-                        match InfractionReasonCommand::precommand_check(
-                            context.clone(),
-                            PrecommandCheckParametersBuilder::new()
-                                .in_memory_cache(cache.clone())
-                                .minimum_permission_level(60).build(),
-                            |ctx, params|
-                                Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                match InfractionReasonCommand::execute_command(context.clone(), arguments, cache).await {
-                                    Ok(()) => {
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "inf reason",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "inf reason",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "inf reason",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "unknown",
-                            error: String::from("command not found.")
-                        }))
-                    }
-                }
-            },
-            Command { name: "mute", arguments, .. } |
-            Command { name: "dmmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "unmute", arguments, .. } |
-            Command { name: "dmunmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmUnmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmUnmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmunmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmunmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmunmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmunmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmUnmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmUnmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmunmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmunmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmunmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "kick", arguments, .. } |
-            Command { name: "dmkick", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmKickCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmKickCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmkick",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmkick",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmkick",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmkick", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmKickCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmKickCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmkick",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmkick",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmkick",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "ban", arguments, .. } |
-            Command { name: "dmban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmBanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmBanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmBanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmBanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "mkick", arguments, .. } |
-            Command { name: "dmmkick", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMkickCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMkickCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmmkick",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmkick",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmkick",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmkick", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMkickCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMkickCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmkick",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmkick",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmkick",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "cleanban", arguments, .. } |
-            Command { name: "dmcleanban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmCleanBanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmCleanBanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmcleanban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmcleanban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmcleanban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmcleanban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmCleanBanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmCleanBanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmcleanban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmcleanban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmcleanban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "unban", arguments, .. } |
-            Command { name: "dmunban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmUnbanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmUnbanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmunban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmunban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmunban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmunban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmUnbanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmUnbanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmunban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmunban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmunban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "tempmute", arguments, .. } |
-            Command { name: "dmtempmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmTempmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmTempmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmtempmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmtempmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "tempmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmtempmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmTempmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmTempmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmtempmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmtempmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmtempmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "mmute", arguments, .. } |
-            Command { name: "dmmmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "munmute", arguments, .. } |
-            Command { name: "dmunmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMunmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMunmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmunmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMunmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMunmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmunmute", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMunmuteCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMunmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmunmute",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmunmute",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmunmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "mwarn", arguments, .. } |
-            Command { name: "dmmwarn", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMwarnCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMwarnCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmmwarn",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmwarn",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmwarn",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmwarn", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMwarnCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMwarnCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmwarn",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmwarn",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmwarn",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "mban", arguments, .. }|
-            Command { name: "dmmban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMbanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMbanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmmban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMbanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMbanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "selfmute", arguments, .. } => {
-                match SelfmuteCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "selfmute",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "selfmute",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "munban", arguments, .. }|
-            Command { name: "dmmunban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match DmMunbanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match DmMbanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "dmmunban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "dmmunban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "dmmunban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "nodmmunban", arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                match NodmMunbanCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new()
-                        .in_memory_cache(cache.clone())
-                        .minimum_permission_level(60).build(),
-                    |ctx, params|
-                        Box::pin(HasRolePermissions::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match NodmMbanCommand::execute_command(context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "nodmmunban",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "nodmmunban",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "nodmmunban",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
+    Ok(true)
+}
 
-            // Owneronly Command Module
-            Command { name: "restart", arguments, .. } => {
-                match RestartCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new().build(),
-                    |ctx, params|
-                        Box::pin(BotOwnerOnly::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match RestartCommand::execute_command(context.clone(), arguments, cache)
-                            .await {
-                            Ok(()) => {
-                                todo!()
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "restart",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "restart",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "stop", arguments, .. } => {
-                match StopCommand::precommand_check(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new().build(),
-                    |ctx, params|
-                        Box::pin(BotOwnerOnly::execute_check(ctx, params))).await {
-                    Ok(()) => {
-                        match StopCommand::execute_command(
-                            context.clone(), arguments, cache).await {
-                            Ok(()) => {
-                                Logger::log_info("Bot stopping...");
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "stop",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "stop",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "support-info", arguments, .. } => {
-                match SupportinfoCommand::precommand_checks(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new().build(),
-                    Box::<[for<'asynchronous_trait>
-                    fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                        -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                            (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                        |ctx, params| BotOwnerOnly::execute_check(ctx, params),
-                        |ctx, params| SupportGuildOnly::execute_check(ctx, params)
-                    ])).await {
-                    Ok(()) => {
-                        match SupportinfoCommand::execute_command(context.clone(), arguments, cache)
-                            .await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "support-info",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "support-info",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "support-info",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "refresh-whitelist-roles", arguments, .. } => {
-                match RefreshWhitelistRolesCommand::precommand_checks(
-                    context.clone(), PrecommandCheckParametersBuilder::new().build(),
-                    Box::<[for<'asynchronous_trait>
-                    fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                        -> Pin<Box<dyn std::future::Future<Output =std::result::Result<
-                            (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>
-                    ::new([
-                        |ctx, params| BotOwnerOnly::execute_check(ctx, params),
-                        |ctx, params| SupportGuildOnly::execute_check(ctx, params)
-                    ])).await {
-                    Ok(()) => {
-                        match RefreshWhitelistRolesCommand::execute_command(context.clone(), arguments,
-                                                                            cache).await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "refresh-whitelist-roles",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "refresh-whitelist-roles",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "refresh-whitelist-roles",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "support-announce", arguments, .. } => {
-                match SupportAnnounceCommand::precommand_checks(
-                    context.clone(),
-                    PrecommandCheckParametersBuilder::new().build(),
-                    Box::<[for<'asynchronous_trait>
-                    fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
-                       -> Pin<Box<dyn std::future::Future<Output = std::result::Result<
-                           (), Box<(dyn Error + Send + Sync)>>> + Send + 'asynchronous_trait>>; 2]>::new([
-                        |ctx, params| BotOwnerOnly::execute_check(ctx, params),
-                        |ctx, params| SupportGuildOnly::execute_check(ctx, params)
-                    ])).await {
-                    Ok(()) => {
-                        match SupportAnnounceCommand::execute_command(context.clone(), arguments, cache)
-                            .await {
-                            Ok(()) => {
-                                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                                    Some(guild) => guild.name,
-                                    None => String::new()
-                                };
-
-                                emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                    command: "support-announce",
-                                    guild_name: guild,
-                                    context: context.clone()
-                                }))
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "support-announce",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "support-announce",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
+async fn handle_command(message: Message,
+                        context: CommandContext<'static>,
+                        prefix: String,
+                        suggestion_prefix: Option<String>,
+                        registry: CommandRegistry,
+                        responses: ResponseTracker,
+                        cache: InMemoryCache,
+                        emitter: CommandEventEmitter) -> Result<(), Box<dyn Error + Send + Sync>> {
+    match context.command_parser.parse_with_prefix(&prefix, &message.content) {
+        Ok(command) => {
+            emitter.event(SystemEvent::CommandIdentified(command.name.to_string())).await;
 
-            // Utilities Command Module
-            Command { name: "emoji", arguments, .. } => {
-                match EmojiCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "emoji",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "emoji",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "randint", arguments, .. } => {
-                match RandintCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "randint",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "randint",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
-            Command { name: "coinflip", arguments, .. } => {
-                match CoinflipCommand::execute_command(context.clone(), arguments, cache).await {
-                    Ok(()) => {
-                        let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                            Some(guild) => guild.name,
-                            None => String::new()
-                        };
-
-                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                            command: "coinflip",
-                            guild_name: guild,
-                            context: context.clone()
-                        }))
-                    },
-                    Err(error) => {
-                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                            command: "coinflip",
-                            error: format!("{}", error)
-                        }))
-                    }
-                }
-            },
+            responses.track(message.id);
 
-            // Whitelist Command Module
-            Command { name: "whitelist", mut arguments, .. } => {
-                let guild = match http_client.guild(message.guild_id.unwrap()).await? {
-                    Some(guild) => guild.name,
-                    None => String::new()
-                };
-
-                let subcommand = arguments.next();
-
-                match subcommand {
-                    Some("accept") => {
-                        match AcceptCommand::precommand_check(
-                            context.clone(), PrecommandCheckParametersBuilder::new().build(),
-                            |ctx, params|
-                                Box::pin(BotOwnerOnly::execute_check(ctx, params))).await {
-                            Ok(()) => {
-                                match AcceptCommand::execute_command(context.clone(), arguments,
-                                                                     cache).await {
-                                    Ok(()) => {
-                                        emitter.event(SystemEvent::CommandExecuted(box CommandExecuted {
-                                            command: "whitelist accept",
-                                            guild_name: guild,
-                                            context: context.clone()
-                                        }))
-                                    },
-                                    Err(error) => {
-                                        emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                            command: "whitelist accept",
-                                            error: format!("{}", error)
-                                        }))
-                                    }
-                                }
-                            },
-                            Err(error) => {
-                                emitter.event(SystemEvent::CommandFailed(box CommandFailed {
-                                    command: "whitelist accept",
-                                    error: format!("{}", error)
-                                }))
-                            }
-                        }
-                    },
-                    _ => {
-                        Logger::log_error(
-                            format!(
-                                "Command '{}' failed due to an error: 'command not found'.", message.content
-                            )
-                        );
-                    }
-                }
-            },
+            let executed = registry.dispatch(command, context.clone(), cache, emitter).await;
 
-            _ => ()
-        }
-    }
-    else {
+            responses.complete(message.id, matches!(executed, Ok(true)));
+            executed?;
+        },
+        Err(error @ CommandParseError::UnknownSubcommand { .. }) => {
+            responses.track(message.id);
+
+            context.http_client.clone().create_message(message.channel_id)
+                .content(format!("<:red_x:705623424675872859> {}", error))?
+                .allowed_mentions()
+                .replied_user(false)
+                .build()
+                .reply(message.id)
+                .await?;
+        },
+        Err(CommandParseError::UnknownCommand { name, suggestions }) => {
+            responses.track(message.id);
+
+            if let Some(suggestion_prefix) = suggestion_prefix && let Some(suggestion) = suggestions.first() {
+                context.http_client.clone().create_message(message.channel_id)
+                    .content(format!("<:red_x:705623424675872859> Unknown command `{0}{1}`; did you mean `{0}{2}`?",
+                                     suggestion_prefix, name, suggestion))?
+                    .allowed_mentions()
+                    .replied_user(false)
+                    .build()
+                    .reply(message.id)
+                    .await?;
+            }
+        },
+        Err(CommandParseError::NoCommand) => responses.track(message.id)
     }
 
     Ok(())
@@ -18,6 +18,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -32,16 +33,40 @@ use crate::utilities::FutureResult;
 crate struct CleanAllCommand;
 
 impl Command for CleanAllCommand {
+    fn name(&self) -> String {
+        String::from("clean")
+    }
+
     fn fully_qualified_name(&self) -> String {
         String::from("clean all")
     }
 
+    fn description(&self) -> String {
+        String::from("Deletes the most recent messages in this channel.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("[amount]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("clean all"), String::from("clean all 50")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let number_of_messages = arguments.next().unwrap_or("25");
+        let number_of_messages = arguments.next().unwrap_or_else(|| String::from("25"));
 
-        Box::pin(administrator_clean_all_command(ctx, number_of_messages.to_string()))
+        Box::pin(administrator_clean_all_command(ctx, number_of_messages))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>,
@@ -75,6 +100,6 @@ async fn administrator_clean_all_command(ctx: CommandContext<'_>, number_of_mess
         ctx.http_client.clone().create_message(channel_id)
             .content(format!("<:red_x:705623424675872859> Invalid limit: `{}`", number_of_messages))?.await?;
 
-        Err(box CommandError("Specified limit cannot be parsed into a u64".to_string()))
+        Err(box CommandError::User("Specified limit cannot be parsed into a u64".to_string()))
     }
 }
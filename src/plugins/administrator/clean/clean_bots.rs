@@ -18,6 +18,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -37,12 +38,32 @@ impl Command for CleanBotsCommand {
     }
 
     fn fully_qualified_name(&self) -> String {
-        String::from("clean user")
+        String::from("clean bots")
+    }
+
+    fn description(&self) -> String {
+        String::from("Deletes the most recent messages sent by bots in this channel.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("[amount]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("clean bots"), String::from("clean bots 20")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
     }
 
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let number = arguments.next().unwrap_or("10").to_string();
+        let number = arguments.next().unwrap_or_else(|| String::from("10"));
 
         Box::pin(administrator_clean_bots_command(ctx, number))
     }
@@ -65,7 +86,7 @@ async fn administrator_clean_bots_command(ctx: CommandContext<'_>, number_of_mes
         ctx.http_client.clone().create_message(channel_id)
             .content(format!("<:red_x:705623424675872859> Invalid limit: `{}`", number_of_messages))?.await?;
 
-        return Err(box CommandError("Number of messages to delete is not a number.".to_string()))
+        return Err(box CommandError::User("Number of messages to delete is not a number.".to_string()))
     };
 
     let message_ids = ctx
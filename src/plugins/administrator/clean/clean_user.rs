@@ -7,10 +7,6 @@ use twilight_cache_inmemory::{
     InMemoryCache,
 };
 
-use twilight_mention::{
-    ParseMention
-};
-
 use twilight_model::{
     id::{
         MessageId,
@@ -23,8 +19,10 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
+    CommandServices,
+    Invocation,
     PrecommandCheckParameters
 };
 
@@ -45,13 +43,39 @@ impl Command for CleanUserCommand {
         String::from("clean user")
     }
 
+    fn description(&self) -> String {
+        String::from("Deletes the most recent messages sent by a user in this channel.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [amount]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("clean user @user"), String::from("clean user 408576714243833867 20")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user = arguments.next().unwrap_or("unknown").to_string();
-        let number = arguments.next().unwrap_or("10").to_string();
-
-        Box::pin(administrator_clean_user_command(ctx, user, number))
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let number = match arguments.next_as_optional::<u64>() {
+            Ok(number) => number.unwrap_or(10),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+
+        Box::pin(administrator_clean_user_command(ctx.services.clone(), Invocation::from(&ctx.message), user_id, number))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>,
@@ -64,48 +88,82 @@ impl Command for CleanUserCommand {
     }
 }
 
-async fn administrator_clean_user_command(ctx: CommandContext<'_>, user: String, number_of_messages: String)
+async fn administrator_clean_user_command(services: CommandServices, invocation: Invocation, user_id: UserId, number: u64)
     -> SystemResult<()> {
-    let channel_id = ctx.message.channel_id;
-    let user_id = if let Ok(u) = UserId::parse(&user) {
-        u
-    }
-    else if let Ok(ru64) = user.parse() {
-        UserId(ru64)
-    }
-    else {
-        ctx.http_client.clone().create_message(channel_id)
-            .content(format!("<:red_x:705623424675872859> Invalid user ID: `{}`", user))?.await?;
-
-        return Err(box CommandError("Invalid user ID.".to_string()))
-    };
-
-    let number = if let Ok(int) = number_of_messages.parse::<u64>() {
-        int
-    }
-    else {
-        ctx.http_client.clone().create_message(channel_id)
-            .content(format!("<:red_x:705623424675872859> Invalid limit: `{}`", number_of_messages))?.await?;
-
-        return Err(box CommandError("Number of messages to delete is not a number.".to_string()));
-    };
-
-    let message_ids = ctx
-        .http_client
-        .clone()
-        .channel_messages(channel_id)
-        .limit(number)?
+    let channel_id = invocation.channel_id;
+    let message_ids = services
+        .discord
+        .channel_messages(channel_id, number)
         .await?
         .iter()
-        .filter(|message| message.author.id == user_id)
+        .filter(|message| message.author_id == user_id)
         .map(|message| message.id)
         .collect::<Vec<MessageId>>();
 
-    ctx.http_client.clone().delete_messages(channel_id, message_ids.clone()).await?;
-    ctx.http_client.clone().create_message(channel_id).content(
+    services.discord.delete_messages(channel_id, message_ids.clone()).await?;
+    services.discord.create_message(channel_id,
         format!(
             "<:green_check:705623382682632205> Deleted `{}` messages sent by user `{}` successfully!"
-            , message_ids.len(), user_id))?.await?;
+            , message_ids.len(), user_id),
+        None).await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::{
+        MessageId,
+        UserId
+    };
+
+    use crate::command_system::{
+        CommandServices
+    };
+
+    use crate::system::{
+        discord_api::{
+            fixtures::{
+                invocation,
+                CHANNEL_ID,
+                USER_ID
+            },
+            DiscordCall,
+            RecordingDiscordApi
+        },
+        stores::{
+            InMemoryConfigStore,
+            InMemoryInfractionStore
+        }
+    };
+
+    use super::administrator_clean_user_command;
+
+    const OTHER_USER_ID: UserId = UserId(6);
+
+    #[tokio::test]
+    async fn deletes_only_recent_messages_of_the_user() {
+        let discord = RecordingDiscordApi::new()
+            .message(CHANNEL_ID, MessageId(10), USER_ID)
+            .message(CHANNEL_ID, MessageId(11), OTHER_USER_ID)
+            .message(CHANNEL_ID, MessageId(12), USER_ID)
+            .message(CHANNEL_ID, MessageId(13), USER_ID)
+            .message(CHANNEL_ID, MessageId(14), OTHER_USER_ID);
+        let services = CommandServices::in_memory(discord.clone(), InMemoryInfractionStore::new(), InMemoryConfigStore::new());
+
+        administrator_clean_user_command(services, invocation(), USER_ID, 4).await.unwrap();
+
+        assert_eq!(discord.calls(), vec![
+            DiscordCall::DeleteMessages {
+                channel_id: CHANNEL_ID,
+                message_ids: vec![MessageId(13), MessageId(12)]
+            },
+            DiscordCall::CreateMessage {
+                channel_id: CHANNEL_ID,
+                content: String::from(
+                    "<:green_check:705623382682632205> Deleted `2` messages sent by user `5` successfully!"),
+                reply_to: None
+            }
+        ]);
+    }
+}
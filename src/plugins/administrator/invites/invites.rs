@@ -11,6 +11,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -29,6 +30,18 @@ impl Command for InvitesCommand {
         String::from("invites")
     }
 
+    fn description(&self) -> String {
+        String::from("Lists the invites of this server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(administrator_invites_command(ctx, cache))
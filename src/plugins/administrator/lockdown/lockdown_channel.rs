@@ -24,15 +24,18 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
 
 use crate::system::{
+    model::payload::LockdownStarted,
     twilight_id_extensions::IntoInnerU64,
     SystemResult,
 };
@@ -50,6 +53,18 @@ impl Command for LockdownChannelCommand {
         String::from("lockdown channel")
     }
 
+    fn description(&self) -> String {
+        String::from("Prevents everyone from sending messages in this channel.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(administrator_lockdown_channel_command(ctx))
@@ -78,6 +93,12 @@ async fn administrator_lockdown_channel_command(ctx: CommandContext<'_>) -> Syst
         .update_channel_permission(channel_id, allow, deny)
         .role(RoleId(ctx.message.guild_id.unwrap().into_inner_u64()))
         .await?;
+
+    ctx.emitter.event(SystemEvent::LockdownStarted(box LockdownStarted {
+        guild_id: ctx.message.guild_id.unwrap(),
+        channel_id: Some(channel_id)
+    })).await;
+
     ctx.http_client.clone().create_reaction(channel_id, ctx.message.id, RequestReactionType::Custom {
         id: EmojiId(705623382682632205),
         name: None
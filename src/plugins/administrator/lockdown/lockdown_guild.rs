@@ -24,15 +24,19 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
+    confirm,
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
 
 use crate::system::{
+    model::payload::LockdownStarted,
     twilight_id_extensions::IntoInnerU64,
     SystemResult,
 };
@@ -50,6 +54,18 @@ impl Command for LockdownGuildCommand {
         String::from("lockdown guild")
     }
 
+    fn description(&self) -> String {
+        String::from("Prevents everyone from sending messages in every channel of this server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(administrator_lockdown_guild_command(ctx, cache))
@@ -69,6 +85,10 @@ impl Command for LockdownGuildCommand {
 }
 
 async fn administrator_lockdown_guild_command(ctx: CommandContext<'_>, cache: InMemoryCache) -> SystemResult<()> {
+    if !confirm(&ctx, "lockdown guild", String::from("This will stop everyone from sending messages in this server.")).await? {
+        return Ok(());
+    }
+
     let role = cache.role(RoleId(ctx.message.guild_id.unwrap().into_inner_u64())).unwrap();
     let mut permissions = role.permissions;
     permissions.remove(Permissions::SEND_MESSAGES);
@@ -76,6 +96,12 @@ async fn administrator_lockdown_guild_command(ctx: CommandContext<'_>, cache: In
     ctx.http_client.update_role(ctx.message.guild_id.unwrap(), role.id)
         .permissions(permissions)
         .await?;
+
+    ctx.emitter.event(SystemEvent::LockdownStarted(box LockdownStarted {
+        guild_id: ctx.message.guild_id.unwrap(),
+        channel_id: None
+    })).await;
+
     ctx.http_client.clone().create_reaction(ctx.message.channel_id, ctx.message.id, RequestReactionType::Custom {
         id: EmojiId(705623382682632205),
         name: None
@@ -24,16 +24,19 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
+    model::payload::LockdownEnded,
     twilight_id_extensions::IntoInnerU64,
     SystemResult,
 };
@@ -51,6 +54,18 @@ impl Command for UnlockdownChannelCommand {
         String::from("unlockdown channel")
     }
 
+    fn description(&self) -> String {
+        String::from("Allows everyone to send messages in this channel again.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(administrator_unlockdown_channel_command(ctx))
@@ -79,6 +94,12 @@ async fn administrator_unlockdown_channel_command(ctx: CommandContext<'_>) -> Sy
         .update_channel_permission(channel_id, allow, deny)
         .role(RoleId(ctx.message.guild_id.unwrap().into_inner_u64()))
         .await?;
+
+    ctx.emitter.event(SystemEvent::LockdownEnded(box LockdownEnded {
+        guild_id: ctx.message.guild_id.unwrap(),
+        channel_id: Some(channel_id)
+    })).await;
+
     ctx.http_client.clone().create_reaction(channel_id, ctx.message.id, RequestReactionType::Custom {
         id: EmojiId(705623382682632205),
         name: None
@@ -24,15 +24,18 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
 
 use crate::system::{
+    model::payload::LockdownEnded,
     twilight_id_extensions::IntoInnerU64,
     SystemResult,
 };
@@ -43,11 +46,23 @@ crate struct UnlockdownGuildCommand;
 
 impl Command for UnlockdownGuildCommand {
     fn name(&self) -> String {
-        String::from("lockdown")
+        String::from("unlockdown")
     }
 
     fn fully_qualified_name(&self) -> String {
-        String::from("lockdown guild")
+        String::from("unlockdown guild")
+    }
+
+    fn description(&self) -> String {
+        String::from("Allows everyone to send messages in every channel of this server again.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
     }
 
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
@@ -76,6 +91,12 @@ async fn administrator_unlockdown_guild_command(ctx: CommandContext<'_>, cache:
     ctx.http_client.update_role(ctx.message.guild_id.unwrap(), role.id)
         .permissions(permissions)
         .await?;
+
+    ctx.emitter.event(SystemEvent::LockdownEnded(box LockdownEnded {
+        guild_id: ctx.message.guild_id.unwrap(),
+        channel_id: None
+    })).await;
+
     ctx.http_client.clone().create_reaction(ctx.message.channel_id, ctx.message.id, RequestReactionType::Custom {
         id: EmojiId(705623382682632205),
         name: None
@@ -20,6 +20,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -42,12 +43,32 @@ impl Command for NicknameChangeCommand {
         String::from("nickname change")
     }
 
+    fn description(&self) -> String {
+        String::from("Changes the nickname of a user.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> <nickname>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nickname change @user New Nickname")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user = arguments.next().unwrap_or("unknown");
+        let user = arguments.next().unwrap_or_else(|| String::from("unknown"));
         let new_nickname = arguments.into_remainder().unwrap_or("");
 
-        Box::pin(administrator_nickname_change_command(ctx, user.to_string(), new_nickname.to_string()))
+        Box::pin(administrator_nickname_change_command(ctx, user, new_nickname.to_string()))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters, check: C)
@@ -73,7 +94,7 @@ async fn administrator_nickname_change_command(ctx: CommandContext<'_>, user: St
             .content(format!("<:red_x:705623424675872859> Invalid user ID encountered: `{}`.", user))?
             .await?;
 
-        return Err(box CommandError("Invalid user ID.".to_string()))
+        return Err(box CommandError::User("Invalid user ID.".to_string()))
     };
 
     let user = if let Some(u) = ctx.http_client.clone().user(user_id).await? {
@@ -85,7 +106,7 @@ async fn administrator_nickname_change_command(ctx: CommandContext<'_>, user: St
             .content(format!("<:red_x:705623424675872859> User `{}` not found.", user))?
             .await?;
 
-        return Err(box CommandError("User not found.".to_string()))
+        return Err(box CommandError::User("User not found.".to_string()))
     };
 
     ctx.http_client
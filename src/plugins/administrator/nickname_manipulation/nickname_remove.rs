@@ -20,6 +20,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -39,7 +40,27 @@ impl Command for NicknameRemoveCommand {
     }
 
     fn fully_qualified_name(&self) -> String {
-        String::from("nickname change")
+        String::from("nickname remove")
+    }
+
+    fn description(&self) -> String {
+        String::from("Removes the nickname of a user.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nickname remove @user")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
     }
 
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
@@ -67,24 +88,14 @@ async fn administrator_nickname_remove_command(ctx: CommandContext<'_>, user: St
         UserId(int)
     }
     else {
-        ctx.http_client.clone()
-            .create_message(channel_id)
-            .content(format!("<:red_x:705623424675872859> Invalid user ID encountered: `{}`.", user))?
-            .await?;
-
-        return Err(box CommandError("Invalid user ID.".to_string()))
+        return Err(box CommandError::User(format!("Invalid user ID encountered: `{}`.", user)))
     };
 
     let user = if let Some(u) = ctx.http_client.clone().user(user_id).await? {
         u
     }
     else {
-        ctx.http_client.clone()
-            .create_message(channel_id)
-            .content(format!("<:red_x:705623424675872859> User `{}` not found.", user))?
-            .await?;
-
-        return Err(box CommandError("User not found.".to_string()))
+        return Err(box CommandError::User(format!("User `{}` not found.", user)))
     };
 
     ctx.http_client
@@ -95,7 +106,7 @@ async fn administrator_nickname_remove_command(ctx: CommandContext<'_>, user: St
         .create_message(channel_id)
         .content(
             format!(
-                "<:red_x:705623424675872859> Successfully removed nickname for user `{}#{}`.",
+                "<:green_check:705623382682632205> Successfully removed nickname for user `{}#{}`.",
                 user.name,
                 user.discriminator
             )
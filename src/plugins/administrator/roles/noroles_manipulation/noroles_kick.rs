@@ -13,7 +13,9 @@ use crate::command_system::{
     parser::{
         Arguments
     },
+    confirm,
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
@@ -32,7 +34,19 @@ impl Command for NorolesKickCommand {
     }
 
     fn fully_qualified_name(&self) -> String {
-        String::from("noroles list")
+        String::from("noroles kick")
+    }
+
+    fn description(&self) -> String {
+        String::from("Kicks every member of this server without any roles.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
     }
 
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
@@ -59,6 +73,10 @@ async fn administrator_noroles_kick_command(ctx: CommandContext<'_>, cache: InMe
             .map(|user_id| cache.member(guild_id, *user_id).unwrap())
             .filter(|member| (*member).roles.is_empty()).collect::<Vec<Arc<CachedMember>>>();
 
+    if !confirm(&ctx, "noroles kick", format!("This will kick {} members with no roles.", members.len())).await? {
+        return Ok(());
+    }
+
     for member in &members {
         ctx.http_client.clone().remove_guild_member(guild_id, member.user.id).await?;
         tokio::time::sleep(std::time::Duration::from_millis(10)).await;
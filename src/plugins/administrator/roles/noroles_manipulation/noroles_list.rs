@@ -14,6 +14,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
@@ -35,6 +36,18 @@ impl Command for NorolesListCommand {
         String::from("noroles list")
     }
 
+    fn description(&self) -> String {
+        String::from("Lists the members of this server without any roles.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send+ 'asynchronous_trait>> {
         Box::pin(administrator_noroles_list_command(ctx, cache))
@@ -22,6 +22,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -36,16 +37,40 @@ use crate::utilities::FutureResult;
 crate struct RoleGlobalRemoveCommand;
 
 impl Command for RoleGlobalRemoveCommand {
+    fn name(&self) -> String {
+        String::from("role")
+    }
+
     fn fully_qualified_name(&self) -> String {
         String::from("role global-remove")
     }
 
+    fn description(&self) -> String {
+        String::from("Removes a role from every member of this server.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<role>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("role global-remove @role")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let role_id = arguments.next().unwrap_or("");
+        let role_id = arguments.next().unwrap_or_default();
 
-        Box::pin(administrator_role_global_remove_command(ctx, role_id.to_string(), cache))
+        Box::pin(administrator_role_global_remove_command(ctx, role_id, cache))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>,
@@ -76,7 +101,7 @@ async fn administrator_role_global_remove_command(ctx: CommandContext<'_>, role_
                 .reply(ctx.message.id)
                 .await?;
 
-            return Err(box CommandError("Role not found.".to_string()));
+            return Err(box CommandError::User("Role not found.".to_string()));
         };
 
         for user_id in guild_members_ids {
@@ -98,6 +123,6 @@ async fn administrator_role_global_remove_command(ctx: CommandContext<'_>, role_
             .reply(ctx.message.id)
             .await?;
 
-        Err(box CommandError("Specified role id is invalid.".to_string()))
+        Err(box CommandError::User("Specified role id is invalid.".to_string()))
     }
 }
@@ -22,6 +22,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -36,15 +37,39 @@ use crate::utilities::FutureResult;
 crate struct RoleRemoveCommand;
 
 impl Command for RoleRemoveCommand {
+    fn name(&self) -> String {
+        String::from("role")
+    }
+
     fn fully_qualified_name(&self) -> String {
         String::from("role remove")
     }
 
+    fn description(&self) -> String {
+        String::from("Removes a role from a user.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> <role> [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("role remove @user @role")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user_id = arguments.next().unwrap_or("").to_string();
-        let role_id = arguments.next().unwrap_or("").to_string();
+        let user_id = arguments.next().unwrap_or_default();
+        let role_id = arguments.next().unwrap_or_default();
         let reason = arguments.into_remainder().unwrap_or("No reason specified.").to_string();
 
         Box::pin(administrator_role_remove_command(ctx, user_id, role_id, reason, cache))
@@ -78,7 +103,7 @@ async fn administrator_role_remove_command(ctx: CommandContext<'_>, user_id: Str
                     .reply(ctx.message.id)
                     .await?;
 
-                return Err(box CommandError("Role not found.".to_string()));
+                return Err(box CommandError::User("Role not found.".to_string()));
             };
 
             ctx.http_client.clone().remove_guild_member_role(guild_id, actual_user_id, actual_role_id).await?;
@@ -98,7 +123,7 @@ async fn administrator_role_remove_command(ctx: CommandContext<'_>, user_id: Str
                 .reply(ctx.message.id)
                 .await?;
 
-            Err(box CommandError("Invalid role ID.".to_string()))
+            Err(box CommandError::User("Invalid role ID.".to_string()))
         }
     }
     else {
@@ -108,6 +133,6 @@ async fn administrator_role_remove_command(ctx: CommandContext<'_>, user_id: Str
             .reply(ctx.message.id)
             .await?;
 
-        Err(box CommandError("Invalid user ID.".to_string()))
+        Err(box CommandError::User("Invalid user ID.".to_string()))
     }
 }
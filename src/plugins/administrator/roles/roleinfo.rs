@@ -27,6 +27,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -45,10 +46,30 @@ impl Command for RoleinfoCommand {
         String::from("role-info")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows information about a role.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<role ID>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("role-info 705623424675872859")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let role_id = arguments.next().unwrap_or("").to_string();
+        let role_id = arguments.next().unwrap_or_default();
 
         Box::pin(administrator_roleinfo_command(ctx, role_id, cache))
     }
@@ -113,7 +134,7 @@ async fn administrator_roleinfo_command(ctx: CommandContext<'_>, role_id: String
                 )?
                 .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
 
-            Err(box CommandError("The role id points to an invalid role.".to_string()))
+            Err(box CommandError::User("The role id points to an invalid role.".to_string()))
         }
     }
     else {
@@ -122,6 +143,6 @@ async fn administrator_roleinfo_command(ctx: CommandContext<'_>, role_id: String
                 format!("<:red_x:705623424675872859> Could not find role with role id `{}` in guild.", role_id))?
             .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
 
-        Err(box CommandError("The specified role_id is invalid.".to_string()))
+        Err(box CommandError::User("The specified role_id is invalid.".to_string()))
     }
 }
@@ -7,10 +7,6 @@ use twilight_cache_inmemory::{
     InMemoryCache,
 };
 
-use twilight_mention::{
-    ParseMention
-};
-
 use twilight_model::{
     id::ChannelId
 };
@@ -20,8 +16,8 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
@@ -44,9 +40,32 @@ impl Command for SlowmodeDisableChannelCommand {
         String::from("slowmode disable channel")
     }
 
-    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
+    fn description(&self) -> String {
+        String::from("Disables the slowmode of a channel.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<channel>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("slowmode disable channel #general")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
+    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let channel_id = arguments.into_remainder().unwrap_or("unknown").to_string();
+        let channel_id = match arguments.next_as::<ChannelId>() {
+            Ok(channel_id) => channel_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
 
         Box::pin(administrator_slowmode_disable_channel_command(ctx, channel_id))
     }
@@ -64,17 +83,7 @@ impl Command for SlowmodeDisableChannelCommand {
     }
 }
 
-async fn administrator_slowmode_disable_channel_command(ctx: CommandContext<'_>, channel: String) -> SystemResult<()> {
-    let channel_id = if let Ok(id) = ChannelId::parse(&channel) {
-        id
-    }
-    else if let Ok(id) = channel.parse() {
-        ChannelId(id)
-    }
-    else {
-        return Err(box CommandError("Invalid channel id.".to_string()))
-    };
-
+async fn administrator_slowmode_disable_channel_command(ctx: CommandContext<'_>, channel_id: ChannelId) -> SystemResult<()> {
     ctx.http_client.clone().update_channel(channel_id).rate_limit_per_user(0)?.await?;
     ctx.http_client
         .clone()
@@ -12,8 +12,8 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
@@ -22,7 +22,6 @@ use crate::system::{
 };
 
 use crate::utilities::{
-    duration::parse_duration,
     FutureResult
 };
 
@@ -37,6 +36,18 @@ impl Command for SlowmodeDisableHereCommand {
         String::from("slowmode disable here")
     }
 
+    fn description(&self) -> String {
+        String::from("Disables the slowmode of this channel.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(administrator_slowmode_disable_here_command(ctx))
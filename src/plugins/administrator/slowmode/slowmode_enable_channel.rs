@@ -1,16 +1,13 @@
 use std::{
     future::Future,
-    pin::Pin
+    pin::Pin,
+    time::Duration
 };
 
 use twilight_cache_inmemory::{
     InMemoryCache,
 };
 
-use twilight_mention::{
-    ParseMention
-};
-
 use twilight_model::{
     id::ChannelId
 };
@@ -20,8 +17,8 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
@@ -30,7 +27,6 @@ use crate::system::{
 };
 
 use crate::utilities::{
-    duration::parse_duration,
     FutureResult
 };
 
@@ -45,10 +41,36 @@ impl Command for SlowmodeEnableChannelCommand {
         String::from("slowmode enable channel")
     }
 
+    fn description(&self) -> String {
+        String::from("Enables the slowmode of a channel.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<channel> [duration]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("slowmode enable channel #general"), String::from("slowmode enable channel #general 30s")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let channel_id = arguments.next().unwrap_or("unknown").to_string();
-        let duration = arguments.into_remainder().unwrap_or("10s").to_string();
+        let channel_id = match arguments.next_as::<ChannelId>() {
+            Ok(channel_id) => channel_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let duration = match arguments.next_as_optional::<Duration>() {
+            Ok(duration) => duration.unwrap_or(Duration::from_secs(10)),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
 
         Box::pin(administrator_slowmode_enable_channel_command(ctx, channel_id, duration))
     }
@@ -66,18 +88,7 @@ impl Command for SlowmodeEnableChannelCommand {
     }
 }
 
-async fn administrator_slowmode_enable_channel_command(ctx: CommandContext<'_>, channel: String, duration: String) -> SystemResult<()> {
-    let duration = parse_duration(duration);
-    let channel_id = if let Ok(id) = ChannelId::parse(&channel) {
-        id
-    }
-    else if let Ok(id) = channel.parse() {
-        ChannelId(id)
-    }
-    else {
-        return Err(box CommandError("Invalid channel id.".to_string()))
-    };
-
+async fn administrator_slowmode_enable_channel_command(ctx: CommandContext<'_>, channel_id: ChannelId, duration: Duration) -> SystemResult<()> {
     ctx.http_client.clone().update_channel(channel_id).rate_limit_per_user(duration.as_secs())?.await?;
     ctx.http_client
         .clone()
@@ -1,6 +1,7 @@
 use std::{
     future::Future,
-    pin::Pin
+    pin::Pin,
+    time::Duration
 };
 
 use twilight_cache_inmemory::{
@@ -12,6 +13,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
@@ -21,7 +23,6 @@ use crate::system::{
 };
 
 use crate::utilities::{
-    duration::parse_duration,
     FutureResult
 };
 
@@ -36,9 +37,32 @@ impl Command for SlowmodeEnableHereCommand {
         String::from("slowmode enable here")
     }
 
-    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
+    fn description(&self) -> String {
+        String::from("Enables the slowmode of this channel.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("[duration]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("slowmode enable here"), String::from("slowmode enable here 1m")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
+    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let duration = arguments.into_remainder().unwrap_or("10s").to_string();
+        let duration = match arguments.next_as_optional::<Duration>() {
+            Ok(duration) => duration.unwrap_or(Duration::from_secs(10)),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
 
         Box::pin(administrator_slowmode_enable_here_command(ctx, duration))
     }
@@ -56,10 +80,9 @@ impl Command for SlowmodeEnableHereCommand {
     }
 }
 
-async fn administrator_slowmode_enable_here_command(ctx: CommandContext<'_>, duration: String) -> SystemResult<()> {
+async fn administrator_slowmode_enable_here_command(ctx: CommandContext<'_>, duration: Duration) -> SystemResult<()> {
     let channel_id = ctx.message.channel_id;
 
-    let duration = parse_duration(duration);
     let seconds = duration.as_secs();
 
     ctx.http_client.clone().update_channel(channel_id).rate_limit_per_user(seconds)?.await?;
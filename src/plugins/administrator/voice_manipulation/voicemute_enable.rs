@@ -28,6 +28,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -47,7 +48,19 @@ impl Command for VoicemuteEnableCommand {
     }
 
     fn fully_qualified_name(&self) -> String {
-        String::from("voicmute enable")
+        String::from("voicemute enable")
+    }
+
+    fn description(&self) -> String {
+        String::from("Mutes every member of the voice channel you are in.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
     }
 
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
@@ -75,7 +88,7 @@ async fn administrator_voicemute_enable_command(ctx: CommandContext<'_>, cache:
         ch
     }
     else {
-        return Err(box CommandError("User not in voice channel".to_string()))
+        return Err(box CommandError::User("User not in voice channel".to_string()))
     };
 
     ctx.http_client
@@ -12,12 +12,12 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::GetGuildConfiguration,
     SystemResult,
 };
 
@@ -36,6 +36,18 @@ impl Command for WebconfigListCommand {
         String::from("webconfig list")
     }
 
+    fn description(&self) -> String {
+        String::from("Lists the users with access to the web configuration of this server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Administrator
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(administrator_webconfig_list_command(ctx))
@@ -53,9 +65,9 @@ impl Command for WebconfigListCommand {
 async fn administrator_webconfig_list_command(ctx: CommandContext<'_>) -> SystemResult<()> {
     let configuration =
         quick_xml::de::from_str::<BotConfig>(
-            ctx.http_client
-                .clone()
-                .get_guild_configuration(ctx.message.guild_id.unwrap())
+            ctx.services
+                .configs
+                .guild_configuration(ctx.message.guild_id.unwrap())
                 .await?
                 .as_str()
         )?;
@@ -22,7 +22,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
 };
 
 use crate::system::{
@@ -36,6 +38,18 @@ impl Command for AboutCommand {
         String::from("about")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows information about the bot.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::General
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
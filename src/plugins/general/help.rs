@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     future::Future,
     pin::Pin
 };
@@ -17,7 +18,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
 };
 
 use crate::system::SystemResult;
@@ -29,30 +32,134 @@ impl Command for HelpCommand {
         String::from("help")
     }
 
-    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments,
-                                            _cache: InMemoryCache)
+    fn description(&self) -> String {
+        String::from("Lists the commands you can run by category, or shows the details of a command.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("[command]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("help"), String::from("help warn"), String::from("help inf search")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::General
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
+    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>,
+                                            cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
-        Box::pin(general_help_command(ctx))
+        let command = arguments.rest();
+
+        Box::pin(general_help_command(ctx, command, cache))
+    }
+}
+
+async fn general_help_command(ctx: CommandContext<'_>, command: Option<String>, cache: InMemoryCache) -> SystemResult<()> {
+    match command {
+        Some(command) => command_help(ctx, command, cache).await,
+        None => categories_help(ctx, cache).await
+    }
+}
+
+async fn categories_help(ctx: CommandContext<'_>, cache: InMemoryCache) -> SystemResult<()> {
+    let registry = ctx.command_registry.clone();
+    let mut categories = BTreeMap::<CommandCategory, Vec<String>>::new();
+
+    for handler in registry.visible_commands(ctx.clone(), cache).await {
+        categories.entry(handler.category).or_default().push(handler.fully_qualified_name.clone());
+    }
+
+    let mut embed = EmbedBuilder::new()
+        .title("HarTex - Commands Reference")?
+        .description("The following list are the commands you can run, by category.")?;
+
+    for (category, mut commands) in categories {
+        commands.sort();
+
+        let commands = commands.iter()
+            .map(|command| format!("`{}`", command))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        embed = embed.field(EmbedFieldBuilder::new(category.to_string(), commands)?);
     }
+
+    let embed = embed
+        .footer(EmbedFooterBuilder::new("Use `help <command>` for the details of a command.")?)
+        .color(0x03_BE_FC)?
+        .build()?;
+
+    ctx.http_client.create_message(ctx.message.channel_id).reply(ctx.message.id).allowed_mentions().replied_user(false)
+        .build().embed(embed)?.await?;
+
+    Ok(())
 }
 
-async fn general_help_command(ctx: CommandContext<'_>) -> SystemResult<()> {
-    let channel_id = ctx.message.channel_id;
-    let embed = EmbedBuilder::new()
-        .title("HarTex - Moderation Commands Reference")?
-        .description(
-            "The following list are the common commands used by the moderators. A more detailed list can be found at the"
-                .to_owned()
-                + " documentation website: SOON:tm:")?
-        .field(EmbedFieldBuilder::new(
-            "warn <User ID> [Reason]", "Adds an infraction to a user with the type `Warning`.")?)
+async fn command_help(ctx: CommandContext<'_>, command: String, cache: InMemoryCache) -> SystemResult<()> {
+    let registry = ctx.command_registry.clone();
+
+    // Commands the user cannot run are reported the same way as commands that do not exist.
+    let handler = match registry.resolve(command.trim()) {
+        Some(handler) if registry.can_run(handler, ctx.clone(), cache).await => handler,
+        _ => {
+            ctx.http_client.clone().create_message(ctx.message.channel_id)
+                .content(format!("<:red_x:705623424675872859> Unknown command `{}`.", command.trim()))?
+                .allowed_mentions()
+                .replied_user(false)
+                .build()
+                .reply(ctx.message.id)
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let usage = format!("{} {}", handler.fully_qualified_name, handler.usage);
+
+    let mut embed = EmbedBuilder::new()
+        .title(format!("HarTex - {}", handler.fully_qualified_name))?
+        .description(handler.description.clone())?
+        .field(EmbedFieldBuilder::new("Usage", format!("`{}`", usage.trim_end()))?)
+        .field(EmbedFieldBuilder::new("Category", handler.category.to_string())?);
+
+    if !handler.examples.is_empty() {
+        let examples = handler.examples.iter()
+            .map(|example| format!("`{}`", example))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        embed = embed.field(EmbedFieldBuilder::new("Examples", examples)?);
+    }
+
+    let aliases = registry.aliases_of(&handler.fully_qualified_name);
+
+    if !aliases.is_empty() {
+        let aliases = aliases.iter()
+            .map(|alias| format!("`{}`", alias))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        embed = embed.field(EmbedFieldBuilder::new("Aliases", aliases)?);
+    }
+
+    if let Some(minimum_permission_level) = handler.checks.minimum_permission_level {
+        embed = embed.field(EmbedFieldBuilder::new("Minimum Permission Level", minimum_permission_level.to_string())?);
+    }
+
+    let embed = embed
         .footer(EmbedFooterBuilder::new(
-            "For parameters wrapped in `<>`, it can either be a user mention or a User ID. For parameters wrapped"
-                .to_owned() + " in `[]`, it is optional.")?)
+            "For parameters wrapped in `<>`, they are required. For parameters wrapped in `[]`, they are optional.")?)
         .color(0x03_BE_FC)?
         .build()?;
 
-    ctx.http_client.create_message(channel_id).reply(ctx.message.id).allowed_mentions().replied_user(false)
+    ctx.http_client.create_message(ctx.message.channel_id).reply(ctx.message.id).allowed_mentions().replied_user(false)
         .build().embed(embed)?.await?;
 
     Ok(())
@@ -23,7 +23,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
 };
 
 use crate::system::SystemResult;
@@ -35,6 +37,18 @@ impl Command for PingCommand {
         String::from("ping")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows the latency of the bot.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::General
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments,
                                             _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
@@ -19,7 +19,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
 };
 
 use crate::system::{
@@ -33,6 +35,18 @@ impl Command for UptimeCommand {
         String::from("uptime")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows how long the bot has been running for.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::General
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
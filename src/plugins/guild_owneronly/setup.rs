@@ -16,14 +16,12 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::{
-        InitializeWhitelistedGuild
-    },
     SystemResult
 };
 
@@ -38,6 +36,14 @@ impl Command for SetupCommand {
         String::from("setup")
     }
 
+    fn description(&self) -> String {
+        String::from("Sets up the configuration of this server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::GuildOwneronly
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
@@ -61,7 +67,7 @@ impl Command for SetupCommand {
 
 async fn guild_owneronly_setup_command(ctx: CommandContext<'_>, guild_id: GuildId)
     -> SystemResult<()> {
-    ctx.http_client.clone().initialize_whitelisted_guild(guild_id).await?;
+    ctx.services.whitelist.initialize_guild(guild_id).await?;
 
     Ok(())
 }
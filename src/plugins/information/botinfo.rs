@@ -18,7 +18,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
 };
 
 use crate::system::{
@@ -34,6 +36,18 @@ impl Command for BotinfoCommand {
         String::from("bot-info")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows information about the bot.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Information
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments,
                                             _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
@@ -35,6 +35,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
 };
 
@@ -53,6 +54,14 @@ impl Command for GuildinfoCommand {
         vec![String::from("server-info")]
     }
 
+    fn description(&self) -> String {
+        String::from("Shows information about this server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Information
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments,
                                             cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
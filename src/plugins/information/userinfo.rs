@@ -53,6 +53,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
 };
 
@@ -67,11 +68,27 @@ impl Command for UserinfoCommand {
         String::from("user-info")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows information about a user, or yourself.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("[user]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("user-info"), String::from("user-info @user")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Information
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let id = arguments.next().unwrap_or("");
-        let user_id = if let Ok(uid) = UserId::parse(<&str>::clone(&id)) {
+        let id = arguments.next().unwrap_or_default();
+        let user_id = if let Ok(uid) = UserId::parse(id.as_str()) {
             Some(uid)
         }
         else {
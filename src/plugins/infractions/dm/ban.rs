@@ -3,17 +3,8 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
-use twilight_mention::{
-    parse::ParseMention,
-};
-
 use twilight_model::{
     id::{
         UserId
@@ -22,20 +13,21 @@ use twilight_model::{
 
 use crate::command_system::{
     parser::{
-        Arguments
+        Arguments,
+        Flag
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
+        infractions::{
+            Infraction,
+            InfractionType
+        }
     },
     SystemResult
 };
@@ -53,23 +45,47 @@ impl Command for BanCommand {
         vec![String::from("dmban")]
     }
 
+    fn description(&self) -> String {
+        String::from("Bans a user, and sends them a direct message about it unless `--silent` is given.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [--days <days>] [--silent] [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("ban @user Raiding"), String::from("ban @user --days 7 --silent Raiding")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let to_parse = arguments.next().unwrap();
-        let user_id = if let Ok(uid) = UserId::parse(to_parse) {
-            Some(uid)
-        }
-        else if let Ok(uid) = to_parse.parse() {
-            Some(UserId(uid))
-        }
-        else {
-            None
+        let flags = match arguments.flags(&[Flag::option("days").short('d'), Flag::switch("silent").short('s')]) {
+            Ok(flags) => flags,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let days = match flags.value_as::<u64>("days") {
+            Ok(days) => days.unwrap_or(0),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let silent = flags.contains("silent");
+
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
         };
 
-        let reason = arguments.into_remainder().unwrap().to_string();
+        let reason = arguments.rest_or("No reason specified");
 
-        Box::pin(infractions_ban_command(ctx, user_id, reason))
+        Box::pin(infractions_ban_command(ctx, user_id, days, silent, reason))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>,
@@ -82,7 +98,8 @@ impl Command for BanCommand {
     }
 }
 
-async fn infractions_ban_command(ctx: CommandContext<'_>, user_id: Option<UserId>, reason: String) -> SystemResult<()> {
+async fn infractions_ban_command(ctx: CommandContext<'_>, user_id: UserId, days: u64, silent: bool, reason: String)
+    -> SystemResult<()> {
     let channel_id = ctx.message.channel_id;
     let guild_id = ctx.message.guild_id.unwrap();
 
@@ -93,40 +110,33 @@ async fn infractions_ban_command(ctx: CommandContext<'_>, user_id: Option<UserId
         "unknown".to_string()
     };
 
-    if let Some(uid) = user_id {
-        let infraction_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id, uid, reason).as_str().as_bytes()));
+    let dm_channel = if silent {
+        None
+    }
+    else {
+        Some(ctx.http_client.clone().create_private_channel(user_id).await?.id)
+    };
 
-        let dm_channel = ctx.http_client.clone().create_private_channel(uid).await?.id;
+    ctx.http_client.clone().create_ban(guild_id, user_id).delete_message_days(days)?.await?;
+    ctx.services.infractions.add_infraction(
+        guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Ban, ctx.author.id)).await?;
 
-        ctx.http_client.clone().create_ban(guild_id, uid).delete_message_days(0)?.await?;
-        ctx.http_client.clone().add_user_infraction(infraction_id, guild_id, uid, reason.clone(),
-                                                    InfractionType::Ban).await?;
+    if let Some(dm_channel) = dm_channel {
         ctx.http_client.clone()
             .create_message(dm_channel)
             .content(
                 format!("You have been banned from guild `{}` (ID: `{}`). Reason: `{}`",
                         guild_name, guild_id.0, reason.clone()))?.await?;
-        ctx.http_client.clone().create_message(channel_id)
-            .content(format!(
-                "<:green_check:705623382682632205> Successfully banned user with ID: `{}` for `{}`", uid, reason))?
-            .allowed_mentions()
-            .replied_user(false)
-            .build()
-            .reply(ctx.message.id)
-            .await?;
-
-        Ok(())
-    }
-    else {
-        ctx.http_client.clone().create_message(channel_id)
-            .content("<:red_x:705623424675872859> The specified user ID is invalid.")?
-            .allowed_mentions()
-            .replied_user(false)
-            .build()
-            .reply(ctx.message.id)
-            .await?;
-
-        Err(box CommandError("User ID cannot be none.".to_string()))
     }
+
+    ctx.http_client.clone().create_message(channel_id)
+        .content(format!(
+            "<:green_check:705623382682632205> Successfully banned user with ID: `{}` for `{}`", user_id, reason))?
+        .allowed_mentions()
+        .replied_user(false)
+        .build()
+        .reply(ctx.message.id)
+        .await?;
+
+    Ok(())
 }
@@ -3,17 +3,8 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
-use twilight_mention::{
-    parse::ParseMention,
-};
-
 use twilight_model::{
     id::{
         UserId
@@ -25,17 +16,17 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
+        infractions::{
+            Infraction,
+            InfractionType
+        }
     },
     SystemResult
 };
@@ -53,14 +44,40 @@ impl Command for CleanBanCommand {
         vec![String::from("dmcleanban")]
     }
 
+    fn description(&self) -> String {
+        String::from("Bans a user and deletes their recent messages, and sends them a direct message about it.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [days] [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("cleanban @user 7 Raiding")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user_id = arguments.next().unwrap().to_string();
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
 
-        let days = arguments.next().unwrap_or("0").to_string();
+        let days = match arguments.next_as_optional::<u64>() {
+            Ok(days) => days.unwrap_or(0),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
 
-        let reason = arguments.into_remainder().unwrap().to_string();
+        let reason = arguments.rest_or("No reason specified");
 
         Box::pin(infractions_clean_ban_command(ctx, user_id, days, reason))
     }
@@ -74,7 +91,7 @@ impl Command for CleanBanCommand {
     }
 }
 
-async fn infractions_clean_ban_command(ctx: CommandContext<'_>, user: String, delete_message_days: String,
+async fn infractions_clean_ban_command(ctx: CommandContext<'_>, user_id: UserId, delete_message_days: u64,
                                        reason: String) -> SystemResult<()> {
     let channel_id = ctx.message.channel_id;
     let guild_id = ctx.message.guild_id.unwrap();
@@ -86,32 +103,11 @@ async fn infractions_clean_ban_command(ctx: CommandContext<'_>, user: String, de
         "unknown".to_string()
     };
 
-    let user_id = if let Ok(user_id) = user.parse::<u64>() {
-        UserId(user_id)
-    }
-    else if let Ok(user_id) = UserId::parse(user.as_str()) {
-        user_id
-    }
-    else {
-        ctx.http_client.clone().create_message(channel_id)
-            .content("<:red_x:705623424675872859> The specified user ID or days of messages to delete is invalid.")?
-            .allowed_mentions()
-            .replied_user(false)
-            .build()
-            .reply(ctx.message.id)
-            .await?;
-
-        return Err(box CommandError("User ID cannot be none.".to_string()));
-    };
-
-    let infraction_id = format!("{:x}", Sha3_224::digest(
-        format!("{}{}{}", guild_id, user_id, reason).as_str().as_bytes()));
-
     let dm_channel = ctx.http_client.clone().create_private_channel(user_id).await?.id;
 
-    ctx.http_client.clone().create_ban(guild_id, user_id).delete_message_days(delete_message_days.parse().unwrap_or(0))?.await?;
-    ctx.http_client.clone().add_user_infraction(infraction_id, guild_id, user_id, reason.clone(),
-                                                InfractionType::Ban).await?;
+    ctx.http_client.clone().create_ban(guild_id, user_id).delete_message_days(delete_message_days)?.await?;
+    ctx.services.infractions.add_infraction(
+        guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Ban, ctx.author.id)).await?;
     ctx.http_client.clone()
         .create_message(dm_channel)
         .content(
@@ -3,11 +3,6 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
@@ -26,6 +21,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -33,14 +29,17 @@ use crate::command_system::{
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        scheduled_action::ScheduledActionKind
     },
     SystemResult
 };
 
+use crate::plugins::infractions::revoke_active_infractions;
+
 use crate::utilities::FutureResult;
 
 crate struct MunbanCommand;
@@ -54,14 +53,34 @@ impl Command for MunbanCommand {
         vec![String::from("dmmunban")]
     }
 
+    fn description(&self) -> String {
+        String::from("Unbans several users, and sends them a direct message about it.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<users...> [--reason <reason>]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("munban 408576714243833867 --reason Appealed")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         let mut users = Vec::<String>::new();
 
         while let Some(string) = arguments.next() {
-            match string {
+            match string.as_str() {
                 "-r" | "--reason" => break,
-                _ => users.push(string.to_string())
+                _ => users.push(string)
             }
         }
 
@@ -80,7 +99,7 @@ impl Command for MunbanCommand {
 }
 
 async fn infractions_munban_command(ctx: CommandContext<'_>, users: Vec<String>, reason: String) -> SystemResult<()> {
-    let mut users_to_ban = Vec::new();
+    let mut users_to_unban = Vec::new();
     let guild_id = ctx.message.guild_id.unwrap();
     let channel_id = ctx.message.channel_id;
 
@@ -93,45 +112,47 @@ async fn infractions_munban_command(ctx: CommandContext<'_>, users: Vec<String>,
 
     for user in users {
         if let Ok(user_id) = UserId::parse(&user) {
-            users_to_ban.push(user_id);
+            users_to_unban.push(user_id);
         }
         else if let Ok(user_id) = user.parse() {
-            users_to_ban.push(UserId(user_id));
+            users_to_unban.push(UserId(user_id));
         }
         else {
-            return Err(box CommandError("Specified User ID is invalid.".to_string()))
+            return Err(box CommandError::User("Specified User ID is invalid.".to_string()))
         }
     };
 
-    for user in users_to_ban {
-        let infraction_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id.0, user.0, reason.clone()).as_bytes()));
-
+    for user in users_to_unban {
         if ctx.author.id != user {
             if let Ok(Some(user_)) = ctx.http_client.user(user).await {
-                ctx.http_client.clone().add_user_infraction(
-                    infraction_id.clone(), ctx.message.guild_id.unwrap(), user,
-                    reason.clone(), InfractionType::Ban).await?;
+                let dm_channel = ctx.http_client.clone().create_private_channel(user).await?;
+
+                ctx.http_client.clone().delete_ban(guild_id, user).await?;
+
+                ctx.services.scheduler.cancel(guild_id, user, |kind| matches!(kind, ScheduledActionKind::Unban)).await?;
+
+                revoke_active_infractions(&ctx, user, |infraction_type| {
+                    matches!(infraction_type, InfractionType::Ban)
+                }, &reason).await?;
+
+                let case_number = ctx.services.infractions.add_infraction(
+                    guild_id, user, Infraction::new(reason.clone(), InfractionType::Unban, ctx.author.id)).await?;
 
                 ctx.http_client.clone().create_message(channel_id).content(
                     format!(
-                        "<:green_check:705623382682632205> Successfully banned user {} (ID: `{}`). Reason: `{}`. Infraction ID: `{}`"
-                        , user_.mention(), user.0, reason.clone(), infraction_id.clone()))?
+                        "<:green_check:705623382682632205> Successfully unbanned user {} (ID: `{}`). Reason: `{}`. Case: `#{}`"
+                        , user_.mention(), user.0, reason.clone(), case_number))?
                     .reply(ctx.message.id).allowed_mentions().replied_user(false).build().await?;
 
-                let dm_channel = ctx.http_client.clone().create_private_channel(user).await?;
-
-                ctx.http_client.clone().create_ban(guild_id, user).await?;
-
                 ctx.http_client.clone().create_message(dm_channel.id).content(
                     format!(
-                        "You are banned from guild {} (ID: `{}`). Reason: `{}`",
+                        "You are unbanned from guild {} (ID: `{}`). Reason: `{}`",
                         guild_name, guild_id.0, reason.clone()
                     ))?.await?;
             }
         }
         else {
-            return Err(box CommandError("Cannot give a warning to the command executor himself/herself.".to_string()))
+            return Err(box CommandError::User("You cannot unban yourself.".to_string()))
         }
     };
 
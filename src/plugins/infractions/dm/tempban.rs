@@ -1,18 +1,17 @@
 use std::{
     future::Future,
-    pin::Pin
+    pin::Pin,
+    time::Duration
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
+use chrono::Utc;
+
+use compound_duration::format_dhms;
 
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
-    Mention,
-    parse::ParseMention,
+    Mention
 };
 
 use twilight_model::{
@@ -27,25 +26,26 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        scheduled_action::{
+            ScheduledAction,
+            ScheduledActionKind
+        }
     },
     SystemResult
 };
 
-use crate::utilities::{
-    duration::parse_duration,
-    FutureResult
-};
+use crate::utilities::FutureResult;
 
 use crate::xml_deserialization::BotConfig;
 
@@ -60,13 +60,39 @@ impl Command for TempbanCommand {
         vec![String::from("dmtempban")]
     }
 
+    fn description(&self) -> String {
+        String::from("Bans a user for a while, and sends them a direct message about it.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [duration] [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("tempban @user 7d Raiding")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user = arguments.next().unwrap().to_string();
-        let duration = arguments.next().unwrap_or("10s").to_string();
-        let reason = arguments.into_remainder().unwrap_or("No reason specified").to_string();
-
-        Box::pin(infractions_tempban_command(ctx, user, duration, reason))
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let duration = match arguments.next_as_optional::<Duration>() {
+            Ok(duration) => duration.unwrap_or(Duration::from_secs(10)),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let reason = arguments.rest_or("No reason specified");
+
+        Box::pin(infractions_tempban_command(ctx, user_id, duration, reason))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters, check: C)
@@ -78,7 +104,7 @@ impl Command for TempbanCommand {
     }
 }
 
-async fn infractions_tempban_command(ctx: CommandContext<'_>, user: String, duration: String, reason: String) -> SystemResult<()> {
+async fn infractions_tempban_command(ctx: CommandContext<'_>, user_id: UserId, duration: Duration, reason: String) -> SystemResult<()> {
     let guild_id = ctx.message.guild_id.unwrap();
 
     let guild_name = if let Ok(Some(guild)) = ctx.http_client.clone().guild(guild_id).await {
@@ -88,44 +114,32 @@ async fn infractions_tempban_command(ctx: CommandContext<'_>, user: String, dura
         "unknown".to_string()
     };
 
-    let user_id = if let Ok(uid) = UserId::parse(user.as_str()) {
-        uid
-    }
-    else if let Ok(uid) = user.parse() {
-        UserId(uid)
-    }
-    else {
-        return Err(box CommandError("The specified User ID is invalid.".to_string()));
-    };
-
-    let infraction_id = format!("{:x}", Sha3_224::digest(
-        format!("{}{}{}", guild_id, user_id, reason.clone()).as_str().as_bytes()));
-
-    let dur = parse_duration(duration.clone());
+    let formatted_duration = format_dhms(duration.as_secs());
+    let expires_at = Utc::now() + chrono::Duration::from_std(duration)?;
 
-    ctx.http_client.clone()
-        .add_user_infraction(infraction_id.clone(), guild_id, user_id, reason.clone(), InfractionType::Ban)
+    let case_number = ctx.services.infractions
+        .add_infraction(
+            guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Ban, ctx.author.id)
+                .expiring_at(expires_at))
         .await?;
 
     ctx.http_client.clone().create_ban(guild_id, user_id).await?;
 
+    ctx.services.scheduler.schedule(ScheduledAction::new(guild_id, user_id, ScheduledActionKind::Unban, expires_at)).await?;
+
     ctx.http_client.clone().create_message(ctx.message.channel_id)
         .content(
             format!(
-                "<:green_check:705623382682632205> Successfully temporarily banned user {} (ID: `{}`) for `{}`. Reason: `{}`. Infraction ID: `{}`",
-                user_id.mention(), user_id.0, duration.clone(), reason, infraction_id))?
+                "<:green_check:705623382682632205> Successfully temporarily banned user {} (ID: `{}`) for `{}`. Reason: `{}`. Case: `#{}`",
+                user_id.mention(), user_id.0, formatted_duration, reason, case_number))?
         .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
 
     let dm_channel = ctx.http_client.clone().create_private_channel(user_id).await?;
     ctx.http_client.clone()
         .create_message(dm_channel.id)
         .content(format!("You have been temporarily banned from guild `{}` for `{}` (ID: `{}`). Reason: `{}`",
-                         guild_name, duration, guild_id.0, reason))?
+                         guild_name, formatted_duration, guild_id.0, reason))?
         .await?;
 
-    tokio::time::sleep(dur).await;
-
-    ctx.http_client.clone().delete_ban(guild_id, user_id).await?;
-
     Ok(())
 }
@@ -1,18 +1,17 @@
 use std::{
     future::Future,
-    pin::Pin
+    pin::Pin,
+    time::Duration
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
+use chrono::Utc;
+
+use compound_duration::format_dhms;
 
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
-    Mention,
-    parse::ParseMention,
+    Mention
 };
 
 use twilight_model::{
@@ -23,30 +22,35 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
+    CommandServices,
+    Invocation,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
-        GetGuildConfiguration
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        payload::MemberMuted,
+        scheduled_action::{
+            ScheduledAction,
+            ScheduledActionKind
+        }
     },
     SystemResult
 };
 
-use crate::utilities::{
-    duration::parse_duration,
-    FutureResult
-};
+use crate::utilities::FutureResult;
 
 use crate::xml_deserialization::BotConfig;
 
@@ -61,13 +65,39 @@ impl Command for TempmuteCommand {
         vec![String::from("dmtempmute")]
     }
 
+    fn description(&self) -> String {
+        String::from("Mutes a user for a while, and sends them a direct message about it.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [duration] [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("tempmute @user 1h Spamming")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user = arguments.next().unwrap().to_string();
-        let duration = arguments.next().unwrap_or("10s").to_string();
-        let reason = arguments.into_remainder().unwrap_or("No reason specified").to_string();
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let duration = match arguments.next_as_optional::<Duration>() {
+            Ok(duration) => duration.unwrap_or(Duration::from_secs(10)),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let reason = arguments.rest_or("No reason specified");
 
-        Box::pin(infractions_tempmute_command(ctx, user, duration, reason))
+        Box::pin(infractions_tempmute_command(ctx.services.clone(), Invocation::from(&ctx.message), user_id, duration, reason))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters, check: C)
@@ -79,75 +109,190 @@ impl Command for TempmuteCommand {
     }
 }
 
-async fn infractions_tempmute_command(ctx: CommandContext<'_>, user: String, duration: String, reason: String) -> SystemResult<()> {
-    let guild_id = ctx.message.guild_id.unwrap();
+async fn infractions_tempmute_command(services: CommandServices, invocation: Invocation, user_id: UserId, duration: Duration,
+                                      reason: String) -> SystemResult<()> {
+    let guild_id = invocation.guild_id.unwrap();
 
-    let guild_name = if let Ok(Some(guild)) = ctx.http_client.clone().guild(guild_id).await {
-        guild.name
-    }
-    else {
-        "unknown".to_string()
+    let guild_name = match services.discord.guild_name(guild_id).await {
+        Ok(Some(name)) => name,
+        _ => "unknown".to_string()
     };
 
-    let user_id = if let Ok(uid) = UserId::parse(user.as_str()) {
-        uid
-    }
-    else if let Ok(uid) = user.parse() {
-        UserId(uid)
-    }
-    else {
-        return Err(box CommandError("The specified User ID is invalid.".to_string()));
-    };
-
-    let guild_config = ctx.http_client.clone().get_guild_configuration(guild_id).await?;
+    let guild_config = services.configs.guild_configuration(guild_id).await?;
     let config = quick_xml::de::from_str::<BotConfig>(guild_config.as_str())?;
 
-    let infraction_id = format!("{:x}", Sha3_224::digest(
-        format!("{}{}{}", guild_id, user_id, reason.clone()).as_str().as_bytes()));
-
-    let dur = parse_duration(duration.clone());
+    let formatted_duration = format_dhms(duration.as_secs());
+    let expires_at = Utc::now() + chrono::Duration::from_std(duration)?;
 
     if let Some(muted_role) = config.plugins.infractions_plugin.mute_command.muted_role {
         let role_id = RoleId(muted_role.role_id);
+        let role_to_restore = config.plugins.infractions_plugin.mute_command.role_to_remove
+            .map(|role_to_remove| RoleId(role_to_remove.role_id));
 
-        ctx.http_client.clone()
-            .add_user_infraction(infraction_id.clone(), guild_id, user_id, reason.clone(), InfractionType::TemporaryMute)
+        let case_number = services.infractions
+            .add_infraction(guild_id, user_id, Infraction::new(reason.clone(), InfractionType::TemporaryMute,
+                                                               invocation.author_id).expiring_at(expires_at))
             .await?;
 
-        ctx.http_client.clone().add_guild_member_role(guild_id, user_id, role_id).await?;
+        services.discord.add_guild_member_role(guild_id, user_id, role_id).await?;
 
-        if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command.role_to_remove {
-            ctx.http_client.clone().remove_guild_member_role(guild_id, user_id, RoleId(role_to_remove.role_id))
-                .await?;
+        services.emitter.event(SystemEvent::MemberMuted(box MemberMuted {
+            guild_id,
+            user_id,
+            duration: Some(duration)
+        })).await;
+
+        if let Some(role_to_remove) = role_to_restore {
+            services.discord.remove_guild_member_role(guild_id, user_id, role_to_remove).await?;
         }
 
-        ctx.http_client.clone().create_message(ctx.message.channel_id)
-            .content(
-                format!(
-                    "<:green_check:705623382682632205> Successfully temporarily muted user {} (ID: `{}`) for `{}`. Reason: `{}`. Infraction ID: `{}`",
-                    user_id.mention(), user_id.0, duration.clone(), reason, infraction_id))?
-            .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
+        services.scheduler.schedule(ScheduledAction::new(guild_id, user_id, ScheduledActionKind::Unmute {
+            muted_role_id: role_id,
+            role_to_restore
+        }, expires_at)).await?;
 
-        let dm_channel = ctx.http_client.clone().create_private_channel(user_id).await?;
+        services.discord.create_message(invocation.channel_id,
+            format!(
+                "<:green_check:705623382682632205> Successfully temporarily muted user {} (ID: `{}`) for `{}`. Reason: `{}`. Case: `#{}`",
+                user_id.mention(), user_id.0, formatted_duration, reason, case_number),
+            Some(invocation.message_id)).await?;
 
-        ctx.http_client.clone()
-            .create_message(dm_channel.id)
-            .content(format!("You have been temporarily muted in guild `{}` for `{}` (ID: `{}`). Reason: `{}`",
-                             guild_name, duration, guild_id.0, reason))?
-            .await?;
+        let dm_channel_id = services.discord.create_private_channel(user_id).await?;
+
+        services.discord.create_message(dm_channel_id,
+            format!("You have been temporarily muted in guild `{}` for `{}` (ID: `{}`). Reason: `{}`",
+                    guild_name, formatted_duration, guild_id.0, reason),
+            None).await?;
 
-        tokio::time::sleep(dur).await;
+        Ok(())
+    }
+    else {
+        Err(box CommandError::User("Muted role is not set.".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        time::Duration
+    };
 
-        ctx.http_client.clone().remove_guild_member_role(guild_id, user_id, role_id).await?;
+    use compound_duration::format_dhms;
 
-        if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command.role_to_remove {
-            ctx.http_client.clone().add_guild_member_role(guild_id, user_id, RoleId(role_to_remove.role_id))
-                .await?;
+    use futures_util::StreamExt;
+
+    use twilight_model::id::{
+        ChannelId,
+        RoleId
+    };
+
+    use crate::command_system::{
+        events::events::SystemEvent,
+        CommandServices
+    };
+
+    use crate::system::{
+        discord_api::{
+            fixtures::{
+                config,
+                invocation,
+                CHANNEL_ID,
+                GUILD_ID,
+                MESSAGE_ID,
+                MODERATOR_ID,
+                USER_ID
+            },
+            DiscordCall,
+            RecordingDiscordApi
+        },
+        model::{
+            infractions::InfractionType,
+            scheduled_action::ScheduledActionKind
+        },
+        stores::{
+            InMemoryConfigStore,
+            InMemoryInfractionStore
         }
+    };
 
-        Ok(())
+    use super::infractions_tempmute_command;
+
+    const MUTED_ROLE_ID: RoleId = RoleId(6);
+    const MEMBER_ROLE_ID: RoleId = RoleId(7);
+
+    #[tokio::test]
+    async fn mutes_the_user_and_schedules_the_unmute() {
+        let discord = RecordingDiscordApi::new().guild(GUILD_ID, "Guild").user(USER_ID);
+        let infractions = InMemoryInfractionStore::new();
+        let configs = InMemoryConfigStore::new().config(GUILD_ID, &config(
+            "<MutedRole><RoleId>6</RoleId></MutedRole><RoleToRemove><RoleId>7</RoleId></RoleToRemove>"));
+        let services = CommandServices::in_memory(discord.clone(), infractions.clone(), configs);
+        let scheduler = services.scheduler.clone();
+        let mut events = services.emitter.subscribe();
+
+        infractions_tempmute_command(services, invocation(), USER_ID, Duration::from_secs(3600), String::from("Spamming"))
+            .await
+            .unwrap();
+
+        let mutes = infractions.infractions_of(GUILD_ID, USER_ID);
+
+        assert_eq!(mutes.len(), 1);
+        assert!(matches!(mutes[0].infraction_type, InfractionType::TemporaryMute));
+        assert_eq!(mutes[0].moderator_id, Some(MODERATOR_ID));
+        assert_eq!(mutes[0].expires_at, Some(scheduler.pending()[0].due_at));
+
+        assert_eq!(discord.calls(), vec![
+            DiscordCall::AddGuildMemberRole {
+                guild_id: GUILD_ID,
+                user_id: USER_ID,
+                role_id: MUTED_ROLE_ID
+            },
+            DiscordCall::RemoveGuildMemberRole {
+                guild_id: GUILD_ID,
+                user_id: USER_ID,
+                role_id: MEMBER_ROLE_ID
+            },
+            DiscordCall::CreateMessage {
+                channel_id: CHANNEL_ID,
+                content: format!(
+                    "<:green_check:705623382682632205> Successfully temporarily muted user <@5> (ID: `5`) for `{}`. Reason: `Spamming`. Case: `#{}`",
+                    format_dhms(3600), mutes[0].infraction_id),
+                reply_to: Some(MESSAGE_ID)
+            },
+            DiscordCall::CreatePrivateChannel {
+                user_id: USER_ID
+            },
+            DiscordCall::CreateMessage {
+                channel_id: ChannelId(USER_ID.0),
+                content: format!("You have been temporarily muted in guild `Guild` for `{}` (ID: `1`). Reason: `Spamming`",
+                                 format_dhms(3600)),
+                reply_to: None
+            }
+        ]);
+
+        let pending = scheduler.pending();
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].user_id, USER_ID);
+        assert_eq!(pending[0].kind, ScheduledActionKind::Unmute {
+            muted_role_id: MUTED_ROLE_ID,
+            role_to_restore: Some(MEMBER_ROLE_ID)
+        });
+
+        assert!(matches!(events.next().await, Some(SystemEvent::MemberMuted(muted)) if muted.user_id == USER_ID));
     }
-    else {
-        Err(box CommandError("Muted role is not set.".to_string()))
+
+    #[tokio::test]
+    async fn fails_without_a_muted_role() {
+        let discord = RecordingDiscordApi::new().guild(GUILD_ID, "Guild").user(USER_ID);
+        let infractions = InMemoryInfractionStore::new();
+        let configs = InMemoryConfigStore::new().config(GUILD_ID, &config(""));
+        let services = CommandServices::in_memory(discord.clone(), infractions.clone(), configs);
+
+        assert!(infractions_tempmute_command(services, invocation(), USER_ID, Duration::from_secs(0), String::from("Spamming"))
+            .await
+            .is_err());
+        assert!(infractions.infractions_of(GUILD_ID, USER_ID).is_empty());
+        assert!(discord.calls().is_empty());
     }
 }
@@ -3,16 +3,10 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
-    Mention,
-    parse::ParseMention,
+    Mention
 };
 
 use twilight_model::{
@@ -26,17 +20,20 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
+    CommandServices,
+    Invocation,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction
+        infractions::{
+            Infraction,
+            InfractionType
+        }
     },
     SystemResult
 };
@@ -54,23 +51,37 @@ impl Command for WarnCommand {
         vec![String::from("dmwarn")]
     }
 
+    fn description(&self) -> String {
+        String::from("Warns a user, and sends them a direct message about it.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("warn @user Spamming")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let id = arguments.next().unwrap_or("");
-        let user_id = if let Ok(uid) = UserId::parse(id) {
-            Some(uid)
-        }
-        else if let Ok(uid) = id.parse() {
-            Some(UserId(uid))
-        }
-        else {
-            None
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
         };
 
-        let remainder = arguments.into_remainder().unwrap_or("No reason specified").to_string();
+        let remainder = arguments.rest_or("No reason specified");
 
-        Box::pin(infractions_warn_command(ctx, user_id, remainder))
+        Box::pin(infractions_warn_command(ctx.services.clone(), Invocation::from(&ctx.message), user_id, remainder))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>,
@@ -83,50 +94,148 @@ impl Command for WarnCommand {
     }
 }
 
-async fn infractions_warn_command(ctx: CommandContext<'_>, id: Option<UserId>, reason: String)
+async fn infractions_warn_command(services: CommandServices, invocation: Invocation, user_id: UserId, reason: String)
                                   -> SystemResult<()> {
-    if let Some(uid) = id {
-        let channel_id = ctx.message.channel_id;
-        let guild_id = ctx.message.guild_id.unwrap();
+    let channel_id = invocation.channel_id;
+    let guild_id = invocation.guild_id.unwrap();
+
+    let guild_name = match services.discord.guild_name(guild_id).await {
+        Ok(Some(name)) => name,
+        _ => "unknown".to_string()
+    };
+
+    if invocation.author_id != user_id {
+        if let Ok(true) = services.discord.user_exists(user_id).await {
+            let case_number = services.infractions.add_infraction(
+                guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Warning, invocation.author_id)).await?;
+
+            services.discord.create_message(channel_id,
+                format!(
+                    "<:green_check:705623382682632205> Successfully warned user {} (ID: `{}`). Reason: `{}`. Case: `#{}`"
+                    , user_id.mention(), user_id.0, reason.clone(), case_number),
+                Some(invocation.message_id)).await?;
+
+            let dm_channel_id = services.discord.create_private_channel(user_id).await?;
+
+            services.discord.create_message(dm_channel_id,
+                format!(
+                    "You received a warning in guild {} (ID: `{}`). Reason: `{}`",
+                    guild_name, guild_id.0, reason.clone()
+                ),
+                None).await?;
+        }
 
-        let guild_name = if let Ok(Some(guild)) = ctx.http_client.clone().guild(guild_id).await {
-            guild.name
+        Ok(())
+    }
+    else {
+        Err(box CommandError::User("Cannot give a warning to the command executor himself/herself.".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::{
+        ChannelId
+    };
+
+    use crate::command_system::{
+        CommandServices
+    };
+
+    use crate::system::{
+        discord_api::{
+            fixtures::{
+                invocation,
+                CHANNEL_ID,
+                GUILD_ID,
+                MESSAGE_ID,
+                MODERATOR_ID,
+                USER_ID
+            },
+            DiscordCall,
+            RecordingDiscordApi
+        },
+        model::infractions::InfractionType,
+        stores::{
+            InMemoryConfigStore,
+            InMemoryInfractionStore
         }
-        else {
-            "unknown".to_string()
-        };
+    };
+
+    use super::infractions_warn_command;
+
+    #[tokio::test]
+    async fn warns_the_user() {
+        let discord = RecordingDiscordApi::new().guild(GUILD_ID, "Guild").user(USER_ID);
+        let infractions = InMemoryInfractionStore::new();
+        let services = CommandServices::in_memory(discord.clone(), infractions.clone(), InMemoryConfigStore::new());
+
+        infractions_warn_command(services, invocation(), USER_ID, String::from("Spamming")).await.unwrap();
+
+        let warnings = infractions.infractions_of(GUILD_ID, USER_ID);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].reason, "Spamming");
+        assert!(matches!(warnings[0].infraction_type, InfractionType::Warning));
+        assert_eq!(warnings[0].infraction_id, "1");
+        assert_eq!(warnings[0].moderator_id, Some(MODERATOR_ID));
+        assert_eq!(warnings[0].status(), "active");
+
+        assert_eq!(discord.calls(), vec![
+            DiscordCall::CreateMessage {
+                channel_id: CHANNEL_ID,
+                content: format!(
+                    "<:green_check:705623382682632205> Successfully warned user <@5> (ID: `5`). Reason: `Spamming`. Case: `#{}`",
+                    warnings[0].infraction_id),
+                reply_to: Some(MESSAGE_ID)
+            },
+            DiscordCall::CreatePrivateChannel {
+                user_id: USER_ID
+            },
+            DiscordCall::CreateMessage {
+                channel_id: ChannelId(USER_ID.0),
+                content: String::from("You received a warning in guild Guild (ID: `1`). Reason: `Spamming`"),
+                reply_to: None
+            }
+        ]);
+    }
 
-        let warning_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id.0, uid.0, reason.clone()).as_bytes()));
+    #[tokio::test]
+    async fn numbers_repeated_warnings_as_separate_cases() {
+        let discord = RecordingDiscordApi::new().guild(GUILD_ID, "Guild").user(USER_ID);
+        let infractions = InMemoryInfractionStore::new();
+        let services = CommandServices::in_memory(discord, infractions.clone(), InMemoryConfigStore::new());
 
-        return if ctx.author.id != uid {
-            if let Ok(Some(user)) = ctx.http_client.user(uid).await {
-                ctx.http_client.clone().add_user_infraction(
-                    warning_id.clone(), ctx.message.guild_id.unwrap(), uid,
-                    reason.clone(), InfractionType::Warning).await?;
+        infractions_warn_command(services.clone(), invocation(), USER_ID, String::from("Spamming")).await.unwrap();
+        infractions_warn_command(services, invocation(), USER_ID, String::from("Spamming")).await.unwrap();
 
-                ctx.http_client.clone().create_message(channel_id).content(
-                    format!(
-                        "<:green_check:705623382682632205> Successfully warned user {} (ID: `{}`). Reason: `{}`. Infraction ID: `{}`"
-                        , user.mention(), uid.0, reason.clone(), warning_id.clone()))?
-                    .reply(ctx.message.id).allowed_mentions().replied_user(false).build().await?;
+        let case_ids = infractions.infractions_of(GUILD_ID, USER_ID).iter()
+            .map(|warning| warning.display_id())
+            .collect::<Vec<_>>();
 
-                let dm_channel = ctx.http_client.clone().create_private_channel(uid).await?;
+        assert_eq!(case_ids, vec!["#1", "#2"]);
+    }
 
-                ctx.http_client.clone().create_message(dm_channel.id).content(
-                    format!(
-                        "You received a warning in guild {} (ID: `{}`). Reason: `{}`",
-                        guild_name, guild_id.0, reason.clone()
-                    ))?.await?;
-            }
+    #[tokio::test]
+    async fn does_not_warn_the_invoker() {
+        let discord = RecordingDiscordApi::new().guild(GUILD_ID, "Guild").user(MODERATOR_ID);
+        let infractions = InMemoryInfractionStore::new();
+        let services = CommandServices::in_memory(discord.clone(), infractions.clone(), InMemoryConfigStore::new());
 
-            Ok(())
-        }
-        else {
-            Err(box CommandError("Cannot give a warning to the command executor himself/herself.".to_string()))
-        }
+        assert!(infractions_warn_command(services, invocation(), MODERATOR_ID, String::from("Spamming")).await.is_err());
+        assert!(infractions.infractions_of(GUILD_ID, MODERATOR_ID).is_empty());
+        assert!(discord.calls().is_empty());
     }
-    else {
-        Err(box CommandError("There is no user to give a warning to.".to_string()))
+
+    #[tokio::test]
+    async fn does_nothing_for_an_unknown_user() {
+        let discord = RecordingDiscordApi::new().guild(GUILD_ID, "Guild");
+        let infractions = InMemoryInfractionStore::new();
+        let services = CommandServices::in_memory(discord.clone(), infractions.clone(), InMemoryConfigStore::new());
+
+        infractions_warn_command(services, invocation(), USER_ID, String::from("Spamming")).await.unwrap();
+
+        assert!(infractions.infractions_of(GUILD_ID, USER_ID).is_empty());
+        assert!(discord.calls().is_empty());
     }
 }
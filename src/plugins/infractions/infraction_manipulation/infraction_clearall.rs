@@ -17,16 +17,15 @@ use crate::command_system::{
     parser::{
         Arguments
     },
+    confirm,
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::{
-        ClearUserInfractions
-    },
     SystemResult
 };
 
@@ -43,11 +42,31 @@ impl Command for InfractionClearallCommand {
         String::from("inf clear-all")
     }
 
+    fn description(&self) -> String {
+        String::from("Removes every infraction of a user.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("inf clear-all @user")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let string = arguments.next().unwrap_or("unknown");
+        let string = arguments.next().unwrap_or_else(|| String::from("unknown"));
 
-        Box::pin(infractions_infraction_clearall_command(ctx, string.to_string()))
+        Box::pin(infractions_infraction_clearall_command(ctx, string))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters, check: C)
@@ -68,10 +87,16 @@ async fn infractions_infraction_clearall_command(ctx: CommandContext<'_>, user:
         UserId(id)
     }
     else {
-        return Err(box CommandError("Specified User ID is invalid.".to_string()))
+        return Err(box CommandError::User("Specified User ID is invalid.".to_string()))
     };
 
-    ctx.http_client.clone().clear_user_infractions(guild_id, user_id).await?;
+    let summary = format!("This will clear every infraction of the user with ID `{}`.", user_id.0);
+
+    if !confirm(&ctx, "inf clear-all", summary).await? {
+        return Ok(());
+    }
+
+    ctx.services.infractions.clear_infractions(guild_id, user_id).await?;
     ctx.http_client
         .clone()
         .create_message(ctx.message.channel_id)
@@ -5,10 +5,6 @@ use std::{
 
 use twilight_cache_inmemory::InMemoryCache;
 
-use twilight_mention::{
-    ParseMention
-};
-
 use twilight_model::{
     id::UserId
 };
@@ -18,15 +14,16 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    model::infraction_update_type::InfractionUpdateType,
-    twilight_http_client_extensions::{
-        UpdateUserInfraction
+    model::{
+        infraction_update_type::InfractionUpdateType,
+        infractions::Infraction
     },
     SystemResult
 };
@@ -44,9 +41,42 @@ impl Command for InfractionReasonCommand {
         String::from("inf reason")
     }
 
-    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
+    fn description(&self) -> String {
+        String::from("Changes the reason of an infraction.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> <infraction ID> <reason>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("inf reason @user #5 Spamming in the general channel")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
+    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
+                                            mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        unimplemented!()
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+
+        let infraction_id = match arguments.next_as::<String>() {
+            Ok(infraction_id) => infraction_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+
+        let new_reason = arguments.rest();
+
+        Box::pin(infractions_infraction_reason(ctx, user_id, infraction_id, new_reason))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters, check: C)
@@ -58,20 +88,17 @@ impl Command for InfractionReasonCommand {
     }
 }
 
-async fn infractions_infraction_reason(ctx: CommandContext<'_>, user_id: String, infraction_id: String, new_reason: String) -> SystemResult<()> {
-    let user_id = if let Ok(id) = UserId::parse(&user_id) {
-        id
-    }
-    else if let Ok(id) = user_id.parse() {
-        UserId(id)
-    }
-    else {
-        return Err(box CommandError("Specified User ID is invalid.".to_string()))
+async fn infractions_infraction_reason(ctx: CommandContext<'_>, user_id: UserId, infraction_id: String,
+                                       new_reason: Option<String>) -> SystemResult<()> {
+    let new_reason = match new_reason {
+        Some(new_reason) => new_reason,
+        None => return Err(box CommandError::User("A new reason is required.".to_string()))
     };
 
-    ctx.http_client
-        .clone()
-        .update_user_infraction(infraction_id, ctx.message.guild_id.unwrap(), user_id, InfractionUpdateType::Reason { new_reason })
+    ctx.services
+        .infractions
+        .update_infraction(ctx.message.guild_id.unwrap(), user_id, infraction_id.clone(),
+                           InfractionUpdateType::Reason { new_reason })
         .await?;
     ctx.http_client
         .clone()
@@ -80,7 +107,8 @@ async fn infractions_infraction_reason(ctx: CommandContext<'_>, user_id: String,
         .replied_user(false)
         .build()
         .reply(ctx.message.id)
-        .content("<:green_check:705623382682632205> Operation successful.")?
+        .content(format!("<:green_check:705623382682632205> The reason of infraction `#{}` is changed.",
+                         Infraction::lookup_id(&infraction_id)))?
         .await?;
 
     Ok(())
@@ -18,15 +18,14 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::{
-        RemoveUserInfraction
-    },
+    model::infractions::Infraction,
     SystemResult
 };
 
@@ -43,12 +42,32 @@ impl Command for InfractionRemoveCommand {
         String::from("inf remove")
     }
 
+    fn description(&self) -> String {
+        String::from("Removes an infraction of a user.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> <infraction ID>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("inf remove @user #5")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         let user_id = match arguments.next() {
             Some(arg) => {
-                if let Ok(id) = UserId::parse(arg) {
+                if let Ok(id) = UserId::parse(&arg) {
                     Some(id)
                 }
                 else if let Ok(int) = arg.parse() {
@@ -61,9 +80,9 @@ impl Command for InfractionRemoveCommand {
             _ => None
         };
 
-        let infraction_id = arguments.next().unwrap_or("");
+        let infraction_id = arguments.next().filter(|infraction_id| !infraction_id.trim().is_empty());
 
-        Box::pin(infractions_remove_command(ctx, user_id, infraction_id.to_string()))
+        Box::pin(infractions_remove_command(ctx, user_id, infraction_id))
     }
 
     fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>,
@@ -76,22 +95,32 @@ impl Command for InfractionRemoveCommand {
     }
 }
 
-async fn infractions_remove_command(ctx: CommandContext<'_>, user_id: Option<UserId>, infraction_id: String)
+async fn infractions_remove_command(ctx: CommandContext<'_>, user_id: Option<UserId>, infraction_id: Option<String>)
     -> SystemResult<()> {
     let channel_id = ctx.message.channel_id;
 
-    if let (Some(gid), Some(uid)) = (ctx.message.guild_id, user_id) {
-        ctx.http_client.clone().remove_user_infraction(gid, uid, infraction_id.clone()).await?;
+    let uid = match user_id {
+        Some(user_id) => user_id,
+        None => return Err(box CommandError::User("Invalid user.".to_string()))
+    };
+
+    let infraction_id = match infraction_id {
+        Some(infraction_id) => infraction_id,
+        None => return Err(box CommandError::User("An infraction ID is required.".to_string()))
+    };
+
+    if let Some(gid) = ctx.message.guild_id {
+        ctx.services.infractions.remove_infraction(gid, uid, infraction_id.clone()).await?;
         ctx.http_client.clone().create_message(channel_id).reply(ctx.message.id)
             .content(
-                format!("<:green_check:705623382682632205> Infraction ID: `{}` is successfully removed.",
-                        infraction_id
+                format!("<:green_check:705623382682632205> Infraction `#{}` is successfully removed.",
+                        Infraction::lookup_id(&infraction_id)
                 )
             )?.allowed_mentions().replied_user(false).build().await?;
 
         Ok(())
     }
     else {
-        Err(box CommandError("Guild ID and UserID cannot be null.".to_string()))
+        Err(box CommandError::Internal("Guild ID cannot be null.".to_string()))
     }
 }
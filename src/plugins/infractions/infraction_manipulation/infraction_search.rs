@@ -3,6 +3,11 @@ use std::{
     pin::Pin
 };
 
+use chrono::{
+    DateTime,
+    Utc
+};
+
 use pad::PadStr;
 
 use twilight_cache_inmemory::InMemoryCache;
@@ -20,20 +25,22 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::{
-        GetLocalUserInfractions
-    },
+    model::infractions::Infraction,
     SystemResult
 };
 
 use crate::utilities::FutureResult;
 
+/// The most characters Discord allows in the content of a message.
+const MESSAGE_LIMIT: usize = 2000;
+
 crate struct InfractionSearchCommand;
 
 impl Command for InfractionSearchCommand {
@@ -45,10 +52,30 @@ impl Command for InfractionSearchCommand {
         String::from("inf search")
     }
 
+    fn description(&self) -> String {
+        String::from("Lists the infractions of a user.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("inf search @user")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let query = arguments.next().unwrap_or("").to_string();
+        let query = arguments.next().unwrap_or_default();
 
         Box::pin(infractions_infraction_search_command(ctx, query))
     }
@@ -72,10 +99,10 @@ async fn infractions_infraction_search_command(ctx: CommandContext<'_>, query: S
             UserId(uid)
     }
     else {
-        return Err(box CommandError("Querying infractions with not a user id is not currently supported.".to_string()));
+        return Err(box CommandError::User("Querying infractions with not a user id is not currently supported.".to_string()));
     };
 
-    let infractions = ctx.http_client.clone().get_local_user_infractions(
+    let infractions = ctx.services.infractions.user_infractions(
         ctx.message.guild_id.unwrap(), user_id).await?;
 
     if infractions.is_empty() {
@@ -84,17 +111,7 @@ async fn infractions_infraction_search_command(ctx: CommandContext<'_>, query: S
             .replied_user(false).build().reply(ctx.message.id).await?;
     }
     else {
-        let mut message: String =
-            "```ID                                                       | Type     | Reason\n".to_owned()
-                + "-------------------------------------------------------- | -------- | ----" +
-                "-------------------------------------------------------------------";
-
-        infractions.iter().for_each(|inf| {
-            message.push_str(format!("\n{} | {} | {}", inf.infraction_id, inf.infraction_type.to_string()
-                .as_str().pad_to_width(8), inf.reason).as_str())
-        });
-
-        message.push_str("\n```");
+        let message = infraction_table(&infractions);
 
         ctx.http_client.clone().create_message(ctx.message.channel_id).content(message)?.allowed_mentions()
             .replied_user(false).build().reply(ctx.message.id).await?;
@@ -102,3 +119,78 @@ async fn infractions_infraction_search_command(ctx: CommandContext<'_>, query: S
 
     Ok(())
 }
+
+/// The table of the infractions of a user, which leaves out the infractions that do not fit in a message.
+fn infraction_table(infractions: &[Infraction]) -> String {
+    let mut message = String::from("```")
+        + "ID       | Type      | Status  | Moderator          | Created              | Expires              | Reason\n"
+        + "-------- | --------- | ------- | ------------------ | -------------------- | -------------------- | ------";
+
+    for (index, inf) in infractions.iter().enumerate() {
+        let moderator = inf.moderator_id.map_or_else(|| "unknown".to_string(), |moderator_id| moderator_id.to_string());
+
+        let mut row = format!("\n{} | {} | {} | {} | {} | {} | {}", inf.display_id().as_str().pad_to_width(8),
+                              inf.infraction_type.to_string().as_str().pad_to_width(9),
+                              inf.status().pad_to_width(7), moderator.as_str().pad_to_width(18),
+                              format_time(inf.created_at, "unknown").as_str().pad_to_width(20),
+                              format_time(inf.expires_at, "never").as_str().pad_to_width(20), inf.reason);
+
+        if let Some(revocation) = &inf.revocation {
+            row.push_str(format!("\n    Revoked by {} on {}. Reason: {}", revocation.revoked_by,
+                                 format_time(Some(revocation.revoked_at), "unknown"), revocation.reason).as_str());
+        }
+
+        let remaining = infractions.len() - index;
+        let footer = format!("\n... and {} more.", remaining);
+
+        // Room is kept for the footer of the infractions left out, and for closing the code block.
+        if message.len() + row.len() + footer.len() + 4 > MESSAGE_LIMIT {
+            message.push_str(&footer);
+
+            break;
+        }
+
+        message.push_str(&row);
+    }
+
+    message.push_str("\n```");
+
+    message
+}
+
+fn format_time(time: Option<DateTime<Utc>>, missing: &str) -> String {
+    time.map_or_else(|| missing.to_string(), |time| time.format("%Y-%m-%d %H:%M UTC").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::UserId;
+
+    use crate::system::model::infractions::{
+        Infraction,
+        InfractionType
+    };
+
+    use super::{
+        infraction_table,
+        MESSAGE_LIMIT
+    };
+
+    #[test]
+    fn leaves_out_the_infractions_that_do_not_fit() {
+        let infractions = (1..=100).map(|case_number| {
+            let mut infraction = Infraction::new("Spamming in the general channel.".to_string(), InfractionType::Warning,
+                                                 UserId(4));
+            infraction.infraction_id = case_number.to_string();
+
+            infraction
+        }).collect::<Vec<_>>();
+
+        let table = infraction_table(&infractions);
+
+        assert!(table.len() <= MESSAGE_LIMIT);
+        assert!(table.contains("#1 "));
+        assert!(!table.contains("#100 "));
+        assert!(table.ends_with("more.\n```"));
+    }
+}
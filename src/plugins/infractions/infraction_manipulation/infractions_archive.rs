@@ -22,13 +22,13 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::GetGuildInfractions,
     twilight_id_extensions::IntoInnerU64,
     SystemResult
 };
@@ -51,6 +51,18 @@ impl Command for InfractionsArchiveCommand {
         String::from("inf archive")
     }
 
+    fn description(&self) -> String {
+        String::from("Exports every infraction of this server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(80)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(infractions_infractions_archive_command(ctx))
@@ -78,20 +90,30 @@ async fn infractions_infractions_archive_command(ctx: CommandContext<'_>) -> Sys
         )?;
     let mut writer = csv::Writer::from_writer(csv_file);
     let infraction_map = ctx
-        .http_client
-        .clone()
-        .get_guild_infractions(ctx.message.guild_id.unwrap())
+        .services
+        .infractions
+        .guild_infractions(ctx.message.guild_id.unwrap())
         .await?;
 
-    writer.write_record(&["User ID", "Infraction ID", "Infraction Type", "Reason"])?;
+    writer.write_record(&["User ID", "Infraction ID", "Infraction Type", "Reason", "Moderator ID", "Created At",
+                          "Expires At", "Status", "Revoked By", "Revoked At", "Revocation Reason"])?;
 
     for (user_id, infractions) in infraction_map {
         for infraction in infractions {
+            let revocation = infraction.revocation.as_ref();
+
             writer.write_record(&[
                 &format!("{}", user_id.into_inner_u64()),
-                &infraction.infraction_id,
+                &infraction.display_id(),
                 &format!("{}", infraction.infraction_type),
-                &infraction.reason
+                &infraction.reason,
+                &infraction.moderator_id.map(|moderator_id| moderator_id.to_string()).unwrap_or_default(),
+                &infraction.created_at.map(|created_at| created_at.to_rfc3339()).unwrap_or_default(),
+                &infraction.expires_at.map(|expires_at| expires_at.to_rfc3339()).unwrap_or_default(),
+                &infraction.status().to_string(),
+                &revocation.map(|revocation| revocation.revoked_by.to_string()).unwrap_or_default(),
+                &revocation.map(|revocation| revocation.revoked_at.to_rfc3339()).unwrap_or_default(),
+                &revocation.map(|revocation| revocation.reason.clone()).unwrap_or_default()
             ])?;
         }
     }
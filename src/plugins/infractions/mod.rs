@@ -2,6 +2,8 @@ crate mod dm;
 crate mod nodm;
 crate mod infraction_manipulation;
 
+mod revocation;
 mod selfmute;
 
+crate use revocation::revoke_active_infractions;
 crate use selfmute::SelfmuteCommand;
@@ -3,17 +3,8 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
-use twilight_mention::{
-    parse::ParseMention,
-};
-
 use twilight_model::{
     id::{
         UserId
@@ -25,17 +16,17 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
+        infractions::{
+            Infraction,
+            InfractionType
+        }
     },
     SystemResult
 };
@@ -49,21 +40,35 @@ impl Command for KickCommand {
         String::from("nodmkick")
     }
 
+    fn description(&self) -> String {
+        String::from("Kicks a user, without sending them a direct message.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nodmkick @user Spamming")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let to_parse = arguments.next().unwrap();
-        let user_id = if let Ok(uid) = UserId::parse(to_parse) {
-            Some(uid)
-        }
-        else if let Ok(uid) = to_parse.parse() {
-            Some(UserId(uid))
-        }
-        else {
-            None
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
         };
 
-        let reason = arguments.into_remainder().unwrap().to_string();
+        let reason = arguments.rest_or("No reason specified");
 
         Box::pin(infractions_kick_command(ctx, user_id, reason))
     }
@@ -77,31 +82,23 @@ impl Command for KickCommand {
     }
 }
 
-async fn infractions_kick_command(ctx: CommandContext<'_>, user_id: Option<UserId>, reason: String)
+async fn infractions_kick_command(ctx: CommandContext<'_>, user_id: UserId, reason: String)
                                   -> SystemResult<()> {
     let guild_id = ctx.message.guild_id.unwrap();
 
-    if let Some(uid)= user_id {
-        let infraction_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id.0, uid.0, reason.clone()).as_bytes()));
-
-        ctx.http_client.clone().add_user_infraction(infraction_id, guild_id, uid, reason.clone(),
-                                                    InfractionType::Kick).await?;
+    ctx.services.infractions.add_infraction(
+        guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Kick, ctx.author.id)).await?;
 
-        ctx.http_client.clone().remove_guild_member(guild_id, uid).await.unwrap();
-        ctx.http_client.clone()
-            .create_message(ctx.message.channel_id)
-            .content(
-                format!("<:green_check:705623382682632205> Successfully kicked user with ID: `{}` for `{}`",
-                        uid, reason))?
-            .allowed_mentions().replied_user(false).build()
-            .reply(ctx.message.id)
-            .await?;
+    ctx.http_client.clone().remove_guild_member(guild_id, user_id).await.unwrap();
+    ctx.http_client.clone()
+        .create_message(ctx.message.channel_id)
+        .content(
+            format!("<:green_check:705623382682632205> Successfully kicked user with ID: `{}` for `{}`",
+                    user_id, reason))?
+        .allowed_mentions().replied_user(false).build()
+        .reply(ctx.message.id)
+        .await?;
 
-        Ok(())
-    }
-    else {
-        Err(box CommandError("User ID cannot be none.".to_string()))
-    }
+    Ok(())
 }
 
@@ -3,11 +3,6 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
@@ -25,7 +20,9 @@ use crate::command_system::{
     parser::{
         Arguments
     },
+    confirm,
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -33,10 +30,10 @@ use crate::command_system::{
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction
+        infractions::{
+            Infraction,
+            InfractionType
+        }
     },
     SystemResult
 };
@@ -50,14 +47,34 @@ impl Command for MbanCommand {
         String::from("nodmmban")
     }
 
+    fn description(&self) -> String {
+        String::from("Bans several users, without sending them a direct message.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<users...> [--reason <reason>]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nodmmban @first @second --reason Raiding")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         let mut users = Vec::<String>::new();
 
         while let Some(string) = arguments.next() {
-            match string {
+            match string.as_str() {
                 "-r" | "--reason" => break,
-                _ => users.push(string.to_string())
+                _ => users.push(string)
             }
         }
 
@@ -88,31 +105,31 @@ async fn infractions_mban_command(ctx: CommandContext<'_>, users: Vec<String>, r
             users_to_ban.push(UserId(user_id));
         }
         else {
-            return Err(box CommandError("Specified User ID is invalid.".to_string()))
+            return Err(box CommandError::User("Specified User ID is invalid.".to_string()))
         }
     };
 
-    for user in users_to_ban {
-        let infraction_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id.0, user.0, reason.clone()).as_bytes()));
+    if !confirm(&ctx, "nodmmban", format!("This will ban {} users.", users_to_ban.len())).await? {
+        return Ok(());
+    }
 
+    for user in users_to_ban {
         if ctx.author.id != user {
             if let Ok(Some(user_)) = ctx.http_client.user(user).await {
-                ctx.http_client.clone().add_user_infraction(
-                    infraction_id.clone(), ctx.message.guild_id.unwrap(), user,
-                    reason.clone(), InfractionType::Ban).await?;
+                let case_number = ctx.services.infractions.add_infraction(
+                    ctx.message.guild_id.unwrap(), user, Infraction::new(reason.clone(), InfractionType::Ban, ctx.author.id)).await?;
 
                 ctx.http_client.clone().create_message(channel_id).content(
                     format!(
-                        "<:green_check:705623382682632205> Successfully banned user {} (ID: `{}`). Reason: `{}`. Infraction ID: `{}`"
-                        , user_.mention(), user.0, reason.clone(), infraction_id.clone()))?
+                        "<:green_check:705623382682632205> Successfully banned user {} (ID: `{}`). Reason: `{}`. Case: `#{}`"
+                        , user_.mention(), user.0, reason.clone(), case_number))?
                     .reply(ctx.message.id).allowed_mentions().replied_user(false).build().await?;
 
                 ctx.http_client.clone().create_ban(guild_id, user).await?;
             }
         }
         else {
-            return Err(box CommandError("Cannot give a warning to the command executor himself/herself.".to_string()))
+            return Err(box CommandError::User("Cannot give a warning to the command executor himself/herself.".to_string()))
         }
     };
 
@@ -4,11 +4,6 @@ use std::{
     time::Duration
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
@@ -26,6 +21,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -33,10 +29,10 @@ use crate::command_system::{
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
+        infractions::{
+            Infraction,
+            InfractionType
+        }
     },
     SystemResult
 };
@@ -50,15 +46,35 @@ impl Command for MkickCommand {
         String::from("nodmmkick")
     }
 
+    fn description(&self) -> String {
+        String::from("Kicks several users, without sending them a direct message.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<users...> [--reason <reason>]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nodmmkick @first @second --reason Spamming")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         let mut users = Vec::<String>::new();
 
         while let Some(string) = arguments.next() {
-            match string {
+            match string.as_str() {
                 "-r" | "--reason" => break,
-                _ => users.push(string.to_string())
+                _ => users.push(string)
             }
         }
 
@@ -89,16 +105,13 @@ async fn infractions_mkick_command(ctx: CommandContext<'_>, users: Vec<String>,
             users_to_kick.push(UserId(user_id));
         }
         else {
-            return Err(box CommandError("Specified User ID is invalid.".to_string()))
+            return Err(box CommandError::User("Specified User ID is invalid.".to_string()))
         }
     }
 
     for user_to_kick in users_to_kick {
-        let infraction_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id.0, user_to_kick.0, reason.clone()).as_bytes()));
-
-        ctx.http_client.clone().add_user_infraction(infraction_id, guild_id, user_to_kick, reason.clone(),
-                                                    InfractionType::Kick).await?;
+        ctx.services.infractions.add_infraction(
+            guild_id, user_to_kick, Infraction::new(reason.clone(), InfractionType::Kick, ctx.author.id)).await?;
         ctx.http_client.remove_guild_member(guild_id, user_to_kick);
         ctx.http_client.clone()
             .create_message(ctx.message.channel_id)
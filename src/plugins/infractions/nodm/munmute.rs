@@ -3,11 +3,6 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
@@ -23,10 +18,12 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -34,15 +31,18 @@ use crate::command_system::{
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
-        GetGuildConfiguration
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        payload::MemberUnmuted,
+        scheduled_action::ScheduledActionKind
     },
     SystemResult
 };
 
+use crate::plugins::infractions::revoke_active_infractions;
+
 use crate::utilities::FutureResult;
 
 use crate::xml_deserialization::BotConfig;
@@ -54,14 +54,34 @@ impl Command for MunmuteCommand {
         String::from("nodmmunmute")
     }
 
+    fn description(&self) -> String {
+        String::from("Unmutes several users, without sending them a direct message.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<users...> [--reason <reason>]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nodmmunmute @first @second --reason Appealed")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         let mut users = Vec::<String>::new();
 
         while let Some(string) = arguments.next() {
-            match string {
+            match string.as_str() {
                 "-r" | "--reason" => break,
-                _ => users.push(string.to_string())
+                _ => users.push(string)
             }
         }
 
@@ -92,27 +112,34 @@ async fn infractions_munmute_command(ctx: CommandContext<'_>, users: Vec<String>
             members_to_mute.push(UserId(user_id));
         }
         else {
-            return Err(box CommandError("Specified User ID is invalid.".to_string()))
+            return Err(box CommandError::User("Specified User ID is invalid.".to_string()))
         }
     }
 
     for member in members_to_mute {
-        let guild_config = ctx.http_client.clone().get_guild_configuration(guild_id).await?;
+        let guild_config = ctx.services.configs.guild_configuration(guild_id).await?;
         let config = quick_xml::de::from_str::<BotConfig>(guild_config.as_str())?;
 
-        let warning_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id, member, reason).as_str().as_bytes()));
-
         if let Some(muted_role) = config.plugins.infractions_plugin.mute_command.muted_role {
             let role_id = RoleId(muted_role.role_id);
 
             if let Ok(Some(user)) = ctx.http_client.user(member).await {
-                ctx.http_client.clone().add_user_infraction(warning_id.clone(),
-                                                            guild_id, member, reason.clone(),
-                                                            InfractionType::Unmute).await?;
+                let case_number = ctx.services.infractions.add_infraction(
+                    guild_id, member, Infraction::new(reason.clone(), InfractionType::Unmute, ctx.author.id)).await?;
 
                 ctx.http_client.clone().remove_guild_member_role(guild_id, member, role_id).await?;
 
+                ctx.services.scheduler.cancel(guild_id, member, |kind| matches!(kind, ScheduledActionKind::Unmute { .. })).await?;
+
+                revoke_active_infractions(&ctx, member, |infraction_type| {
+                    matches!(infraction_type, InfractionType::Mute | InfractionType::TemporaryMute)
+                }, &reason).await?;
+
+                ctx.emitter.event(SystemEvent::MemberUnmuted(box MemberUnmuted {
+                    guild_id,
+                    user_id: member
+                })).await;
+
                 if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command
                     .role_to_remove {
                     ctx.http_client.clone().add_guild_member_role(guild_id, member,
@@ -121,22 +148,13 @@ async fn infractions_munmute_command(ctx: CommandContext<'_>, users: Vec<String>
                 ctx.http_client.clone().create_message(ctx.message.channel_id)
                     .content(
                         format!(
-                            "<:green_check:705623382682632205> Successfully unmurws user {} (ID: `{}`). Reason: `{}`. Infraction ID: `{}`",
-                            user.mention(), member.0, reason, warning_id))?
+                            "<:green_check:705623382682632205> Successfully unmurws user {} (ID: `{}`). Reason: `{}`. Case: `#{}`",
+                            user.mention(), member.0, reason, case_number))?
                     .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
             }
         }
         else {
-            ctx.http_client
-                .clone().create_message(channel_id)
-                .content("<:red_x:705623424675872859> Muted role is not set.")?
-                .allowed_mentions()
-                .replied_user(false)
-                .build()
-                .reply(ctx.message.id)
-                .await?;
-
-            return Err(box CommandError("Muted role is not set.".to_string()))
+            return Err(box CommandError::User("Muted role is not set.".to_string()))
         }
     }
 
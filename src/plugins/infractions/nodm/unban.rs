@@ -3,17 +3,8 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
-use twilight_mention::{
-    parse::ParseMention,
-};
-
 use twilight_model::{
     id::{
         UserId
@@ -25,21 +16,24 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
-    CommandError,
     PrecommandCheckParameters
 };
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        scheduled_action::ScheduledActionKind
     },
     SystemResult
 };
 
+use crate::plugins::infractions::revoke_active_infractions;
+
 use crate::utilities::FutureResult;
 
 crate struct UnbanCommand;
@@ -49,21 +43,35 @@ impl Command for UnbanCommand {
         String::from("nodmunban")
     }
 
+    fn description(&self) -> String {
+        String::from("Unbans a user, without sending them a direct message.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nodmunban 408576714243833867 Appealed")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let to_parse = arguments.next().unwrap();
-        let user_id = if let Ok(uid) = UserId::parse(to_parse) {
-            Some(uid)
-        }
-        else if let Ok(uid) = to_parse.parse() {
-            Some(UserId(uid))
-        }
-        else {
-            None
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
         };
 
-        let reason = arguments.into_remainder().unwrap().to_string();
+        let reason = arguments.rest_or("No reason specified");
 
         Box::pin(infractions_unban_command(ctx, user_id, reason))
     }
@@ -78,7 +86,7 @@ impl Command for UnbanCommand {
     }
 }
 
-async fn infractions_unban_command(ctx: CommandContext<'_>, user_id: Option<UserId>, reason: String) -> SystemResult<()> {
+async fn infractions_unban_command(ctx: CommandContext<'_>, user_id: UserId, reason: String) -> SystemResult<()> {
     let channel_id = ctx.message.channel_id;
     let guild_id = ctx.message.guild_id.unwrap();
 
@@ -89,35 +97,25 @@ async fn infractions_unban_command(ctx: CommandContext<'_>, user_id: Option<User
         "unknown".to_string()
     };
 
-    if let Some(uid) = user_id {
-        let infraction_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id, uid, reason).as_str().as_bytes()));
+    ctx.services.infractions.add_infraction(
+        guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Unban, ctx.author.id)).await?;
 
-        ctx.http_client.clone().add_user_infraction(infraction_id, guild_id, uid, reason.clone(),
-                                                    InfractionType::Unban).await?;
+    ctx.http_client.clone().delete_ban(guild_id, user_id).await?;
 
-        ctx.http_client.clone().delete_ban(guild_id, uid).await?;
+    ctx.services.scheduler.cancel(guild_id, user_id, |kind| matches!(kind, ScheduledActionKind::Unban)).await?;
 
-        ctx.http_client.clone().create_message(channel_id)
-            .content(format!(
-                "<:green_check:705623382682632205> Successfully unbanned user with ID: `{}` for `{}`", uid, reason))?
-            .allowed_mentions()
-            .replied_user(false)
-            .build()
-            .reply(ctx.message.id)
-            .await?;
+    revoke_active_infractions(&ctx, user_id, |infraction_type| {
+        matches!(infraction_type, InfractionType::Ban)
+    }, &reason).await?;
 
-        Ok(())
-    }
-    else {
-        ctx.http_client.clone().create_message(channel_id)
-            .content("<:red_x:705623424675872859> The specified user ID is invalid.")?
-            .allowed_mentions()
-            .replied_user(false)
-            .build()
-            .reply(ctx.message.id)
-            .await?;
-
-        Err(box CommandError("User ID cannot be none.".to_string()))
-    }
+    ctx.http_client.clone().create_message(channel_id)
+        .content(format!(
+            "<:green_check:705623382682632205> Successfully unbanned user with ID: `{}` for `{}`", user_id, reason))?
+        .allowed_mentions()
+        .replied_user(false)
+        .build()
+        .reply(ctx.message.id)
+        .await?;
+
+    Ok(())
 }
@@ -3,16 +3,10 @@ use std::{
     pin::Pin
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
-
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
-    Mention,
-    parse::ParseMention,
+    Mention
 };
 
 use twilight_model::{
@@ -23,10 +17,12 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -34,15 +30,18 @@ use crate::command_system::{
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
-        GetGuildConfiguration
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        payload::MemberUnmuted,
+        scheduled_action::ScheduledActionKind
     },
     SystemResult
 };
 
+use crate::plugins::infractions::revoke_active_infractions;
+
 use crate::utilities::FutureResult;
 
 use crate::xml_deserialization::BotConfig;
@@ -54,21 +53,35 @@ impl Command for UnmuteCommand {
         String::from("nodmunmute")
     }
 
+    fn description(&self) -> String {
+        String::from("Unmutes a user, without sending them a direct message.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("nodmunmute @user Appealed")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
+    fn minimum_permission_level(&self) -> Option<u32> {
+        Some(60)
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let to_parse = arguments.next().unwrap();
-        let user_id = if let Ok(uid) = UserId::parse(to_parse) {
-            Some(uid)
-        }
-        else if let Ok(uid) = to_parse.parse() {
-            Some(UserId(uid))
-        }
-        else {
-            None
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
         };
 
-        let reason = arguments.into_remainder().unwrap().to_string();
+        let reason = arguments.rest_or("No reason specified");
 
         Box::pin(infractions_unmute_command(ctx, user_id, reason))
     }
@@ -83,48 +96,50 @@ impl Command for UnmuteCommand {
     }
 }
 
-async fn infractions_unmute_command(ctx: CommandContext<'_>, user_id: Option<UserId>,
+async fn infractions_unmute_command(ctx: CommandContext<'_>, user_id: UserId,
                                     reason: String)
     -> SystemResult<()> {
     let guild_id = ctx.message.guild_id.unwrap();
 
-    if let Some(uid) = user_id {
-        let guild_config = ctx.http_client.clone().get_guild_configuration(guild_id).await?;
-        let config = quick_xml::de::from_str::<BotConfig>(guild_config.as_str())?;
+    let guild_config = ctx.services.configs.guild_configuration(guild_id).await?;
+    let config = quick_xml::de::from_str::<BotConfig>(guild_config.as_str())?;
+
+    if let Some(muted_role) = config.plugins.infractions_plugin.mute_command
+        .muted_role {
+        let role_id = RoleId(muted_role.role_id);
 
-        let warning_id = format!("{:x}", Sha3_224::digest(
-            format!("{}{}{}", guild_id, uid, reason).as_str().as_bytes()));
+        if let Ok(Some(user)) = ctx.http_client.user(user_id).await {
+            ctx.http_client.clone().remove_guild_member_role(guild_id, user_id, role_id).await?;
 
-        if let Some(muted_role) = config.plugins.infractions_plugin.mute_command
-            .muted_role {
-            let role_id = RoleId(muted_role.role_id);
+            ctx.services.scheduler.cancel(guild_id, user_id, |kind| matches!(kind, ScheduledActionKind::Unmute { .. })).await?;
 
-            if let Ok(Some(user)) = ctx.http_client.user(uid).await {
-                ctx.http_client.clone().remove_guild_member_role(guild_id, uid, role_id).await?;
+            revoke_active_infractions(&ctx, user_id, |infraction_type| {
+                matches!(infraction_type, InfractionType::Mute | InfractionType::TemporaryMute)
+            }, &reason).await?;
 
-                ctx.http_client.clone().add_user_infraction(
-                    warning_id.clone(), guild_id, uid, reason.clone(),
-                    InfractionType::Unmute).await?;
+            ctx.emitter.event(SystemEvent::MemberUnmuted(box MemberUnmuted {
+                guild_id,
+                user_id
+            })).await;
 
-                if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command
-                    .role_to_remove {
-                    ctx.http_client.clone().add_guild_member_role(guild_id, uid,
-                                                                  RoleId(role_to_remove.role_id)).await?;
-                }
+            let case_number = ctx.services.infractions.add_infraction(
+                guild_id, user_id, Infraction::new(reason.clone(), InfractionType::Unmute, ctx.author.id)).await?;
 
-                ctx.http_client.clone().create_message(ctx.message.channel_id).content(
-                    format!(
-                        "<:green_check:705623382682632205> Successfully unmuted user {} (ID: `{}`). Reason: `{}`. Infraction ID: `{}`",
-                        user.mention(), uid.0, reason, warning_id))?
-                    .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
+            if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command
+                .role_to_remove {
+                ctx.http_client.clone().add_guild_member_role(guild_id, user_id,
+                                                              RoleId(role_to_remove.role_id)).await?;
             }
 
-            Ok(())
-        } else {
-            Err(box CommandError("Muted role is not set.".to_string()))
+            ctx.http_client.clone().create_message(ctx.message.channel_id).content(
+                format!(
+                    "<:green_check:705623382682632205> Successfully unmuted user {} (ID: `{}`). Reason: `{}`. Case: `#{}`",
+                    user.mention(), user_id.0, reason, case_number))?
+                .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
         }
-    }
-    else {
-        Err(box CommandError("User ID cannot be none.".to_string()))
+
+        Ok(())
+    } else {
+        Err(box CommandError::User("Muted role is not set.".to_string()))
     }
 }
@@ -0,0 +1,33 @@
+use twilight_model::{
+    id::UserId
+};
+
+use crate::command_system::CommandContext;
+
+use crate::system::{
+    model::{
+        infraction_update_type::InfractionUpdateType,
+        infractions::InfractionType
+    },
+    SystemResult
+};
+
+/// Revokes the active infractions of a member that are lifted by another, such as the mutes an unmute ends.
+crate async fn revoke_active_infractions(ctx: &CommandContext<'_>, user_id: UserId,
+                                         lifted: impl Fn(InfractionType) -> bool, reason: &str) -> SystemResult<()> {
+    let guild_id = ctx.message.guild_id.unwrap();
+    let infractions = ctx.services.infractions.user_infractions(guild_id, user_id).await?;
+
+    for infraction in infractions {
+        if infraction.active && lifted(infraction.infraction_type) {
+            ctx.services.infractions
+                .update_infraction(guild_id, user_id, infraction.infraction_id, InfractionUpdateType::Revoke {
+                    revoked_by: ctx.author.id,
+                    reason: reason.to_string()
+                })
+                .await?;
+        }
+    }
+
+    Ok(())
+}
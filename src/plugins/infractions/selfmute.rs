@@ -1,18 +1,17 @@
 use std::{
     future::Future,
-    pin::Pin
+    pin::Pin,
+    time::Duration
 };
 
-use sha3::{
-    Digest,
-    Sha3_224
-};
+use chrono::Utc;
+
+use compound_duration::format_dhms;
 
 use twilight_cache_inmemory::InMemoryCache;
 
 use twilight_mention::{
-    Mention,
-    parse::ParseMention,
+    Mention
 };
 
 use twilight_model::{
@@ -23,10 +22,12 @@ use twilight_model::{
 };
 
 use crate::command_system::{
+    events::events::SystemEvent,
     parser::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -34,19 +35,20 @@ use crate::command_system::{
 
 use crate::system::{
     model::{
-        infractions::InfractionType
-    },
-    twilight_http_client_extensions::{
-        AddUserInfraction,
-        GetGuildConfiguration
+        infractions::{
+            Infraction,
+            InfractionType
+        },
+        payload::MemberMuted,
+        scheduled_action::{
+            ScheduledAction,
+            ScheduledActionKind
+        }
     },
     SystemResult
 };
 
-use crate::utilities::{
-    duration::parse_duration,
-    FutureResult
-};
+use crate::utilities::FutureResult;
 
 use crate::xml_deserialization::BotConfig;
 
@@ -57,17 +59,39 @@ impl Command for SelfmuteCommand {
         String::from("selfmute")
     }
 
+    fn description(&self) -> String {
+        String::from("Mutes yourself for a while.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<user> [duration] [reason]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("selfmute @user 1h Studying")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Infractions
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
                                             -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let user = arguments.next().unwrap().to_string();
-        let duration = arguments.next().unwrap_or("10s").to_string();
-        let reason = arguments.into_remainder().unwrap_or("No reason specified").to_string();
-
-        Box::pin(infractions_selfmute_command(ctx, user, duration, reason))
+        let user_id = match arguments.next_as::<UserId>() {
+            Ok(user_id) => user_id,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let duration = match arguments.next_as_optional::<Duration>() {
+            Ok(duration) => duration.unwrap_or(Duration::from_secs(10)),
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+        let reason = arguments.rest_or("No reason specified");
+
+        Box::pin(infractions_selfmute_command(ctx, user_id, duration, reason))
     }
 }
 
-async fn infractions_selfmute_command(ctx: CommandContext<'_>, user: String, duration: String, reason: String) -> SystemResult<()> {
+async fn infractions_selfmute_command(ctx: CommandContext<'_>, user_id: UserId, duration: Duration, reason: String) -> SystemResult<()> {
     let guild_id = ctx.message.guild_id.unwrap();
 
     let guild_name = if let Ok(Some(guild)) = ctx.http_client.clone().guild(guild_id).await {
@@ -77,43 +101,45 @@ async fn infractions_selfmute_command(ctx: CommandContext<'_>, user: String, dur
         "unknown".to_string()
     };
 
-    let user_id = if let Ok(uid) = UserId::parse(user.as_str()) {
-        uid
-    }
-    else if let Ok(uid) = user.parse() {
-        UserId(uid)
-    }
-    else {
-        return Err(box CommandError("The specified User ID is invalid.".to_string()));
-    };
-
-    let guild_config = ctx.http_client.clone().get_guild_configuration(guild_id).await?;
+    let guild_config = ctx.services.configs.guild_configuration(guild_id).await?;
     let config = quick_xml::de::from_str::<BotConfig>(guild_config.as_str())?;
 
-    let infraction_id = format!("{:x}", Sha3_224::digest(
-        format!("{}{}{}", guild_id, user_id, reason.clone()).as_str().as_bytes()));
-
-    let dur = parse_duration(duration.clone());
+    let formatted_duration = format_dhms(duration.as_secs());
+    let expires_at = Utc::now() + chrono::Duration::from_std(duration)?;
 
     if let Some(muted_role) = config.plugins.infractions_plugin.mute_command.muted_role {
         let role_id = RoleId(muted_role.role_id);
+        let role_to_restore = config.plugins.infractions_plugin.mute_command.role_to_remove
+            .map(|role_to_remove| RoleId(role_to_remove.role_id));
 
-        ctx.http_client.clone()
-            .add_user_infraction(infraction_id.clone(), guild_id, user_id, reason.clone(), InfractionType::TemporaryMute)
+        let case_number = ctx.services.infractions
+            .add_infraction(
+                guild_id, user_id, Infraction::new(reason.clone(), InfractionType::TemporaryMute, ctx.author.id)
+                    .expiring_at(expires_at))
             .await?;
 
         ctx.http_client.clone().add_guild_member_role(guild_id, user_id, role_id).await?;
 
-        if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command.role_to_remove {
-            ctx.http_client.clone().remove_guild_member_role(guild_id, user_id, RoleId(role_to_remove.role_id))
-                .await?;
+        ctx.emitter.event(SystemEvent::MemberMuted(box MemberMuted {
+            guild_id,
+            user_id,
+            duration: Some(duration)
+        })).await;
+
+        if let Some(role_to_remove) = role_to_restore {
+            ctx.http_client.clone().remove_guild_member_role(guild_id, user_id, role_to_remove).await?;
         }
 
+        ctx.services.scheduler.schedule(ScheduledAction::new(guild_id, user_id, ScheduledActionKind::Unmute {
+            muted_role_id: role_id,
+            role_to_restore
+        }, expires_at)).await?;
+
         ctx.http_client.clone().create_message(ctx.message.channel_id)
             .content(
                 format!(
-                    "<:green_check:705623382682632205> Successfully temporarily self-muted user {} (ID: `{}`) for `{}`. Reason: `{}`. Infraction ID: `{}`",
-                    user_id.mention(), user_id.0, duration.clone(), reason, infraction_id))?
+                    "<:green_check:705623382682632205> Successfully temporarily self-muted user {} (ID: `{}`) for `{}`. Reason: `{}`. Case: `#{}`",
+                    user_id.mention(), user_id.0, formatted_duration, reason, case_number))?
             .allowed_mentions().replied_user(false).build().reply(ctx.message.id).await?;
 
         let dm_channel = ctx.http_client.clone().create_private_channel(user_id).await?;
@@ -121,22 +147,13 @@ async fn infractions_selfmute_command(ctx: CommandContext<'_>, user: String, dur
         ctx.http_client.clone()
             .create_message(dm_channel.id)
             .content(format!("You have been temporarily self-muted in guild `{}` for `{}` (ID: `{}`). Reason: `{}`",
-                             guild_name, duration, guild_id.0, reason))?
+                             guild_name, formatted_duration, guild_id.0, reason))?
             .await?;
 
-        tokio::time::sleep(dur).await;
-
-        ctx.http_client.clone().remove_guild_member_role(guild_id, user_id, role_id).await?;
-
-        if let Some(role_to_remove) = config.plugins.infractions_plugin.mute_command.role_to_remove {
-            ctx.http_client.clone().add_guild_member_role(guild_id, user_id, RoleId(role_to_remove.role_id))
-                .await?;
-        }
-
         Ok(())
     }
     else {
-        Err(box CommandError("Muted role is not set.".to_string()))
+        Err(box CommandError::User("Muted role is not set.".to_string()))
     }
 }
 
@@ -0,0 +1,174 @@
+use std::{
+    future::Future,
+    pin::Pin
+};
+
+use twilight_cache_inmemory::InMemoryCache;
+
+use twilight_embed_builder::{
+    EmbedBuilder,
+    EmbedFieldBuilder
+};
+
+use crate::command_system::{
+    parser::{
+        Arguments
+    },
+    Command,
+    CommandCategory,
+    CommandContext,
+    CommandScope,
+    PrecommandCheckParameters
+};
+
+use crate::system::{
+    SystemResult
+};
+
+use crate::utilities::FutureResult;
+
+/// The most characters Discord accepts in the value of an embed field.
+const FIELD_VALUE_LIMIT: usize = 1024;
+
+/// The most characters Discord accepts in an embed, counting its title and the names and values of its fields.
+const EMBED_LIMIT: usize = 6000;
+
+crate struct ErrorCommand;
+
+impl Command for ErrorCommand {
+    fn fully_qualified_name(&self) -> String {
+        String::from("error")
+    }
+
+    fn description(&self) -> String {
+        String::from("Shows the details of an error reported to a user with an error code.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<code>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("error 3FA94C0D12B7")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Owneronly
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
+    fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
+                                            mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
+        -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
+        let code = match arguments.next_as::<String>() {
+            Ok(code) => code,
+            Err(error) => return Box::pin(FutureResult::err(box error))
+        };
+
+        Box::pin(owneronly_error_command(ctx, code))
+    }
+
+    fn precommand_check<'asynchronous_trait, C>(ctx: CommandContext<'asynchronous_trait>, params: PrecommandCheckParameters, check: C)
+        -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>>
+        where
+            C: Fn(CommandContext<'asynchronous_trait>, PrecommandCheckParameters)
+                -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
+        Box::pin(FutureResult::resolve(check(ctx, params)))
+    }
+}
+
+async fn owneronly_error_command(ctx: CommandContext<'_>, code: String) -> SystemResult<()> {
+    let report = match ctx.services.error_reports.error_report(code.to_uppercase()).await? {
+        Some(report) => report,
+        None => {
+            ctx.http_client.clone().create_message(ctx.message.channel_id)
+                .content(format!("<:red_x:705623424675872859> No error was reported with code `{}`.", code))?
+                .allowed_mentions()
+                .replied_user(false)
+                .build()
+                .reply(ctx.message.id)
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let arguments = if report.arguments.is_empty() {
+        String::from("*None*")
+    }
+    else {
+        format!("`{}`", truncate(&report.arguments, FIELD_VALUE_LIMIT - 2))
+    };
+
+    let guild = match report.guild_id {
+        Some(guild_id) => format!("`{}`", guild_id.0),
+        None => String::from("*Direct Messages*")
+    };
+
+    let error_chain = report.error_chain.iter()
+        .enumerate()
+        .map(|(idx, error)| format!("{}. {}", idx + 1, error))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let title = format!("Error Report `{}`", report.code);
+    let fields = vec![
+        ("Reported At", report.reported_at.format("%Y-%m-%d %H:%M:%S UTC").to_string(), false),
+        ("Command", format!("`{}`", truncate(&report.command, FIELD_VALUE_LIMIT - 2)), true),
+        ("Arguments", arguments, true),
+        ("Guild", guild, true),
+        ("Channel", format!("`{}`", report.channel_id.0), true),
+        ("User", format!("`{}`", report.user_id.0), true)
+    ];
+
+    // The error chain is the longest part of a report, so it takes whatever is left of the embed.
+    let used = title.chars().count() + "Error Chain".len() + fields.iter()
+        .map(|(name, value, _)| name.chars().count() + value.chars().count())
+        .sum::<usize>();
+    let error_chain_limit = FIELD_VALUE_LIMIT.min(EMBED_LIMIT.saturating_sub(used)).saturating_sub("```\n\n```".len());
+
+    let mut embed = EmbedBuilder::new().title(title)?;
+
+    for (name, value, inline) in fields {
+        let field = EmbedFieldBuilder::new(name, value)?;
+
+        embed = embed.field(if inline { field.inline() } else { field });
+    }
+
+    let embed = embed
+        .field(EmbedFieldBuilder::new("Error Chain", format!("```\n{}\n```", truncate(&error_chain, error_chain_limit)))?)
+        .color(0x03_BE_FC)?
+        .build()?;
+
+    ctx.http_client.create_message(ctx.message.channel_id).reply(ctx.message.id).allowed_mentions().replied_user(false)
+        .build().embed(embed)?.await?;
+
+    Ok(())
+}
+
+/// Shortens the text to at most the given number of characters, ending it with an ellipsis when it is cut.
+fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_string();
+    }
+
+    let mut truncated = text.chars().take(limit.saturating_sub(1)).collect::<String>();
+    truncated.push('…');
+
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate;
+
+    #[test]
+    fn truncates_long_text_to_the_limit() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("é".repeat(20).as_str(), 10).chars().count(), 10);
+        assert!(truncate(&"x".repeat(2000), 1024).ends_with('…'));
+    }
+}
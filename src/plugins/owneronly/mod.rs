@@ -1,9 +1,11 @@
+mod error;
 mod refresh_whitelist_roles;
 mod restart;
 mod stop;
 mod supportannounce;
 mod supportinfo;
 
+crate use error::ErrorCommand;
 crate use refresh_whitelist_roles::RefreshWhitelistRolesCommand;
 crate use restart::RestartCommand;
 crate use stop::StopCommand;
@@ -17,15 +17,12 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
 
 use crate::system::{
-    twilight_http_client_extensions::{
-        GetGuildConfiguration,
-        GetWhitelistedGuilds
-    },
     SystemResult
 };
 
@@ -48,6 +45,14 @@ impl Command for RefreshWhitelistRolesCommand {
         String::from("refresh-whitelist-roles")
     }
 
+    fn description(&self) -> String {
+        String::from("Refreshes the roles of the whitelisted server owners in the support server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Owneronly
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             _arguments: Arguments<'asynchronous_trait>, cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
@@ -70,12 +75,12 @@ impl Command for RefreshWhitelistRolesCommand {
 
 async fn owneronly_refresh_whitelist_roles_command(ctx: CommandContext<'_>, cache: InMemoryCache)
     -> SystemResult<()> {
-    let whitelisted_guilds = ctx.http_client.clone().get_whitelisted_guilds().await?;
+    let whitelisted_guilds = ctx.services.whitelist.whitelisted_guilds().await?;
     let current_guild_id = ctx.message.guild_id.unwrap();
     let current_guild_users = cache.guild_members(current_guild_id).unwrap();
 
     for guild_id in whitelisted_guilds {
-        let guild_config_str = ctx.http_client.clone().get_guild_configuration(guild_id).await?;
+        let guild_config_str = ctx.services.configs.guild_configuration(guild_id).await?;
         let config = quick_xml::de::from_str::<BotConfig>(guild_config_str.as_str())?;
         let owner = cache.guild(guild_id).expect("Guild not found (barely happen!)").owner_id;
 
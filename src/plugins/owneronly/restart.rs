@@ -10,6 +10,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     CommandError,
     PrecommandCheckParameters
@@ -28,6 +29,14 @@ impl Command for RestartCommand {
         String::from("restart")
     }
 
+    fn description(&self) -> String {
+        String::from("Restarts the bot.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Owneronly
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output = SystemResult<()>> + Send + 'asynchronous_trait>> {
@@ -45,6 +54,6 @@ impl Command for RestartCommand {
 }
 
 async fn owneronly_restart_command(_ctx: CommandContext<'_>) -> SystemResult<()> {
-    Err(box CommandError("Not going to be implemented yet.".to_string()))
+    Err(box CommandError::User("Not going to be implemented yet.".to_string()))
 }
 
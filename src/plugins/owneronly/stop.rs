@@ -10,6 +10,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
@@ -27,6 +28,14 @@ impl Command for StopCommand {
         String::from("stop")
     }
 
+    fn description(&self) -> String {
+        String::from("Stops the bot.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Owneronly
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>,
                                             _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
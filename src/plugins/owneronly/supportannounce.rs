@@ -26,6 +26,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
@@ -41,6 +42,22 @@ impl Command for SupportAnnounceCommand {
         String::from("support-announce")
     }
 
+    fn description(&self) -> String {
+        String::from("Sends an announcement to the support server.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("--title <title> --description <description>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("support-announce --title Maintenance --description The bot restarts in 5 minutes.")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Owneronly
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         let mut title_done = false;
@@ -57,11 +74,11 @@ impl Command for SupportAnnounceCommand {
             }
             else {
                 if !title_done {
-                    title.push_str(next);
+                    title.push_str(&next);
                     title.push(' ');
                 }
                 else {
-                    description.push_str(next);
+                    description.push_str(&next);
                     description.push(' ');
                 }
             }
@@ -26,7 +26,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
     CommandError
 };
 
@@ -41,6 +43,18 @@ impl Command for CoinflipCommand {
         String::from("coinflip")
     }
 
+    fn description(&self) -> String {
+        String::from("Flips a coin.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Utilities
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
         Box::pin(utilities_coinflip_command(ctx))
@@ -16,7 +16,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
     CommandError
 };
 
@@ -38,9 +40,29 @@ impl Command for EmojiCommand {
         String::from("emoji")
     }
 
+    fn description(&self) -> String {
+        String::from("Shows information about a custom emoji.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("<emoji>")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("emoji :red_x:")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Utilities
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let emoji = arguments.next().unwrap_or("unknown").to_string();
+        let emoji = arguments.next().unwrap_or_else(|| String::from("unknown"));
 
         Box::pin(utilities_emoji_command(ctx, emoji))
     }
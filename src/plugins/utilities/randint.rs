@@ -27,7 +27,9 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
+    CommandScope,
     CommandError
 };
 
@@ -42,10 +44,30 @@ impl Command for RandintCommand {
         String::from("randint")
     }
 
+    fn description(&self) -> String {
+        String::from("Picks a random whole number in a range.")
+    }
+
+    fn usage(&self) -> String {
+        String::from("[start] [end]")
+    }
+
+    fn examples(&self) -> Vec<String> {
+        vec![String::from("randint"), String::from("randint 1 100")]
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Utilities
+    }
+
+    fn scope(&self) -> CommandScope {
+        CommandScope::Anywhere
+    }
+
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, mut arguments: Arguments<'asynchronous_trait>, _cache: InMemoryCache)
         -> Pin<Box<dyn Future<Output=SystemResult<()>> + Send + 'asynchronous_trait>> {
-        let start = arguments.next().unwrap_or("1").parse().unwrap_or(1);
-        let end = arguments.next().unwrap_or("10").parse().unwrap_or(10);
+        let start = arguments.next().and_then(|argument| argument.parse().ok()).unwrap_or(1);
+        let end = arguments.next().and_then(|argument| argument.parse().ok()).unwrap_or(10);
 
         Box::pin(utilities_randint_command(ctx, start, end))
     }
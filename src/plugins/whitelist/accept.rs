@@ -10,6 +10,7 @@ use crate::command_system::{
         Arguments
     },
     Command,
+    CommandCategory,
     CommandContext,
     PrecommandCheckParameters
 };
@@ -23,8 +24,20 @@ use crate::utilities::FutureResult;
 crate struct AcceptCommand;
 
 impl Command for AcceptCommand {
+    fn name(&self) -> String {
+        String::from("whitelist")
+    }
+
     fn fully_qualified_name(&self) -> String {
-        String::from("accept")
+        String::from("whitelist accept")
+    }
+
+    fn description(&self) -> String {
+        String::from("Accepts the whitelist application of a server.")
+    }
+
+    fn category(&self) -> CommandCategory {
+        CommandCategory::Whitelist
     }
 
     fn execute_command<'asynchronous_trait>(ctx: CommandContext<'asynchronous_trait>, _arguments: Arguments,
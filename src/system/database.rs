@@ -0,0 +1,151 @@
+use std::{
+    env::*,
+    str::FromStr,
+    time::Duration
+};
+
+use sqlx::postgres::{
+    PgPool,
+    PgPoolOptions
+};
+
+use crate::logging::logger::Logger;
+
+use crate::system::{
+    Stopwatch,
+    SystemError,
+    SystemResult
+};
+
+/// How the connection pools of the databases are sized, which is read from the environment; a setting that is not set
+/// takes its default.
+#[derive(Copy, Clone, Debug, PartialEq)]
+crate struct DatabaseConfiguration {
+    /// The most connections each pool keeps open, set by `PGSQL_POOL_MAX_CONNECTIONS`.
+    crate max_connections: u32,
+
+    /// How long to wait for a connection before giving up, in seconds in `PGSQL_POOL_CONNECT_TIMEOUT`.
+    crate connect_timeout: Duration,
+
+    /// How long an unused connection is kept open, in seconds in `PGSQL_POOL_IDLE_TIMEOUT`.
+    crate idle_timeout: Duration
+}
+
+impl DatabaseConfiguration {
+    crate fn from_environment() -> SystemResult<Self> {
+        let defaults = Self::default();
+
+        Ok(Self {
+            max_connections: setting("PGSQL_POOL_MAX_CONNECTIONS", defaults.max_connections)?,
+            connect_timeout: Duration::from_secs(
+                setting("PGSQL_POOL_CONNECT_TIMEOUT", defaults.connect_timeout.as_secs())?),
+            idle_timeout: Duration::from_secs(setting("PGSQL_POOL_IDLE_TIMEOUT", defaults.idle_timeout.as_secs())?)
+        })
+    }
+}
+
+impl Default for DatabaseConfiguration {
+    fn default() -> Self {
+        Self {
+            max_connections: 5,
+            connect_timeout: Duration::from_secs(30),
+            idle_timeout: Duration::from_secs(600)
+        }
+    }
+}
+
+fn setting<T: FromStr>(name: &str, default: T) -> SystemResult<T> {
+    parse_setting(name, var(name).ok(), default)
+}
+
+/// Parses the value of a setting, or takes its default when it is not set.
+fn parse_setting<T: FromStr>(name: &str, value: Option<String>, default: T) -> SystemResult<T> {
+    match value {
+        Some(value) => match value.trim().parse() {
+            Ok(value) => Ok(value),
+            Err(_) => Err(box SystemError(format!("`{}` is not a valid value for {}.", value, name)))
+        },
+        None => Ok(default)
+    }
+}
+
+/// The connection pool of each database, which are created once at startup and shared by every request.
+///
+/// The whitelisted guilds and the error reports are kept in the guilds database, and the infractions and the scheduled
+/// actions that end them in the guild infractions database.
+#[derive(Clone)]
+crate struct DatabasePools {
+    crate guilds: PgPool,
+    crate guild_configuration: PgPool,
+    crate guild_infractions: PgPool
+}
+
+impl DatabasePools {
+    crate async fn connect(configuration: DatabaseConfiguration) -> SystemResult<Self> {
+        Ok(Self {
+            guilds: connect("PGSQL_CREDENTIALS_GUILDS", configuration).await?,
+            guild_configuration: connect("PGSQL_CREDENTIALS_GUILD_CONFIGURATION", configuration).await?,
+            guild_infractions: connect("PGSQL_CREDENTIALS_GUILD_INFRACTIONS", configuration).await?
+        })
+    }
+
+    /// Checks that every database answers a query, so that a database that is down is noticed at startup rather
+    /// than by the first command that needs it.
+    crate async fn health_check(&self) -> SystemResult<()> {
+        for &(name, pool) in self.named().iter() {
+            let stopwatch = Stopwatch::new();
+
+            if let Err(error) = sqlx::query("SELECT 1; --").execute(pool).await {
+                return Err(box SystemError(format!("The {} database is unreachable. Error: {}", name, error)));
+            }
+
+            Logger::log_debug(format!("The {} database answered in {} ms.", name, stopwatch.elapsed_milliseconds()));
+        }
+
+        Ok(())
+    }
+
+    fn named(&self) -> [(&'static str, &PgPool); 3] {
+        [
+            ("guilds", &self.guilds),
+            ("guild configuration", &self.guild_configuration),
+            ("guild infractions", &self.guild_infractions)
+        ]
+    }
+}
+
+async fn connect(credentials_variable: &str, configuration: DatabaseConfiguration) -> SystemResult<PgPool> {
+    let database_credentials = match var(credentials_variable) {
+        Ok(credentials) => credentials,
+        Err(_) => return Err(box SystemError(format!("{} is not set.", credentials_variable)))
+    };
+
+    Ok(PgPoolOptions::new()
+        .max_connections(configuration.max_connections)
+        .connect_timeout(configuration.connect_timeout)
+        .idle_timeout(configuration.idle_timeout)
+        .connect(&database_credentials)
+        .await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{
+        parse_setting,
+        DatabaseConfiguration
+    };
+
+    #[test]
+    fn takes_defaults_for_unset_settings() {
+        assert_eq!(parse_setting("PGSQL_POOL_MAX_CONNECTIONS", None, 5).unwrap(), 5);
+        assert_eq!(DatabaseConfiguration::default().connect_timeout, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn parses_set_settings() {
+        assert_eq!(parse_setting("PGSQL_POOL_MAX_CONNECTIONS", Some(" 20 ".to_string()), 5).unwrap(), 20);
+        assert!(parse_setting::<u32>("PGSQL_POOL_MAX_CONNECTIONS", Some("many".to_string()), 5).is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId,
+        MessageId,
+        UserId
+    }
+};
+
+use crate::command_system::Invocation;
+
+/// The guild that commands are invoked in by the tests.
+crate const GUILD_ID: GuildId = GuildId(1);
+
+/// The channel that commands are invoked in by the tests.
+crate const CHANNEL_ID: ChannelId = ChannelId(2);
+
+/// The message that invokes the commands.
+crate const MESSAGE_ID: MessageId = MessageId(3);
+
+/// The author of the message that invokes the commands.
+crate const MODERATOR_ID: UserId = UserId(4);
+
+/// The user that commands are invoked on.
+crate const USER_ID: UserId = UserId(5);
+
+/// An invocation of a command by the moderator, in the channel of the guild.
+crate fn invocation() -> Invocation {
+    Invocation {
+        guild_id: Some(GUILD_ID),
+        channel_id: CHANNEL_ID,
+        message_id: MESSAGE_ID,
+        author_id: MODERATOR_ID
+    }
+}
+
+/// A guild configuration, with the given content of the `MuteCommand` of the infractions plugin.
+crate fn config(mute_command: &str) -> String {
+    format!(
+        "<BotConfig><Dashboard><DashboardUser><Id>4</Id><PermissionInteger>3</PermissionInteger></DashboardUser></Dashboard>\
+         <Plugins><InfractionsPlugin><MuteCommand>{}</MuteCommand></InfractionsPlugin></Plugins></BotConfig>",
+        mute_command)
+}
@@ -0,0 +1,95 @@
+use std::{
+    error::Error,
+    fmt::{
+        Display,
+        Formatter,
+        Result as FmtResult
+    },
+    future::Future,
+    pin::Pin
+};
+
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId,
+        MessageId,
+        RoleId,
+        UserId
+    }
+};
+
+use crate::system::SystemResult;
+
+#[cfg(test)]
+crate mod fixtures;
+#[cfg(test)]
+crate mod recording;
+mod twilight;
+
+#[cfg(test)]
+crate use recording::{
+    DiscordCall,
+    DiscordFailure,
+    RecordingDiscordApi
+};
+
+crate type DiscordFuture<'a, T> = Pin<Box<dyn Future<Output = SystemResult<T>> + Send + 'a>>;
+
+/// The member, ban or role that an operation is on does not exist, such as because the member left the guild; unlike
+/// other failures, retrying the operation does not help.
+#[derive(Debug, Clone)]
+crate struct UnknownTarget(crate String);
+
+impl Display for UnknownTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for UnknownTarget {}
+
+/// A message of a channel, as much of it as commands read.
+#[derive(Debug, Copy, Clone, PartialEq)]
+crate struct ChannelMessage {
+    crate id: MessageId,
+    crate author_id: UserId
+}
+
+/// The Discord operations that commands make, so that commands can be run against a fake in tests instead of
+/// Discord.
+///
+/// Only the commands that are tested go through it, namely `warn`, `tempmute` and `clean user` along with their
+/// variants, and the scheduler that ends temporary mutes and bans; the other commands call Twilight directly, and are
+/// moved onto it as they gain tests, adding the operations they need.
+crate trait DiscordApi: Send + Sync {
+    /// The name of a guild, if the bot can see it.
+    fn guild_name(&self, guild_id: GuildId) -> DiscordFuture<'_, Option<String>>;
+
+    /// Whether a user exists.
+    fn user_exists(&self, user_id: UserId) -> DiscordFuture<'_, bool>;
+
+    /// Sends a message to a channel, as a reply to another message in the channel if one is given; the author of
+    /// that message is not mentioned.
+    fn create_message(&self, channel_id: ChannelId, content: String, reply_to: Option<MessageId>)
+        -> DiscordFuture<'_, ()>;
+
+    /// Opens the direct message channel with a user.
+    fn create_private_channel(&self, user_id: UserId) -> DiscordFuture<'_, ChannelId>;
+
+    /// Adds a role to a member, failing with `UnknownTarget` if the member or role does not exist.
+    fn add_guild_member_role(&self, guild_id: GuildId, user_id: UserId, role_id: RoleId) -> DiscordFuture<'_, ()>;
+
+    /// Removes a role from a member, failing with `UnknownTarget` if the member or role does not exist.
+    fn remove_guild_member_role(&self, guild_id: GuildId, user_id: UserId, role_id: RoleId) -> DiscordFuture<'_, ()>;
+
+    fn create_ban(&self, guild_id: GuildId, user_id: UserId) -> DiscordFuture<'_, ()>;
+
+    /// Lifts the ban of a user, failing with `UnknownTarget` if the user is not banned.
+    fn delete_ban(&self, guild_id: GuildId, user_id: UserId) -> DiscordFuture<'_, ()>;
+
+    /// The most recent messages of a channel, newest first.
+    fn channel_messages(&self, channel_id: ChannelId, limit: u64) -> DiscordFuture<'_, Vec<ChannelMessage>>;
+
+    fn delete_messages(&self, channel_id: ChannelId, message_ids: Vec<MessageId>) -> DiscordFuture<'_, ()>;
+}
@@ -0,0 +1,241 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex
+    }
+};
+
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId,
+        MessageId,
+        RoleId,
+        UserId
+    }
+};
+
+use crate::command_system::CommandError;
+use crate::system::SystemResult;
+
+use super::{
+    ChannelMessage,
+    DiscordApi,
+    DiscordFuture,
+    UnknownTarget
+};
+
+/// A change that a command made through a `RecordingDiscordApi`.
+#[derive(Debug, Clone, PartialEq)]
+crate enum DiscordCall {
+    CreateMessage {
+        channel_id: ChannelId,
+        content: String,
+        reply_to: Option<MessageId>
+    },
+    CreatePrivateChannel {
+        user_id: UserId
+    },
+    AddGuildMemberRole {
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId
+    },
+    RemoveGuildMemberRole {
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId
+    },
+    CreateBan {
+        guild_id: GuildId,
+        user_id: UserId
+    },
+    DeleteBan {
+        guild_id: GuildId,
+        user_id: UserId
+    },
+    DeleteMessages {
+        channel_id: ChannelId,
+        message_ids: Vec<MessageId>
+    }
+}
+
+/// How a change made through a `RecordingDiscordApi` fails.
+#[derive(Debug, Copy, Clone, PartialEq)]
+crate enum DiscordFailure {
+    /// A failure that may not happen again, such as Discord being unavailable.
+    Transient,
+
+    /// The member, ban or role that the change is on does not exist.
+    UnknownTarget
+}
+
+/// An in-memory Discord, which answers reads from the guilds, users and messages it was given and records every
+/// change made through it in order.
+///
+/// The direct message channel of a user has the same ID as the user. Changes can be made to fail, in which case they
+/// are not recorded.
+#[derive(Clone, Default)]
+crate struct RecordingDiscordApi {
+    guilds: HashMap<GuildId, String>,
+    users: Vec<UserId>,
+    messages: HashMap<ChannelId, Vec<ChannelMessage>>,
+    calls: Arc<Mutex<Vec<DiscordCall>>>,
+    failures: Arc<Mutex<Vec<DiscordFailure>>>
+}
+
+impl RecordingDiscordApi {
+    crate fn new() -> Self {
+        Self::default()
+    }
+
+    crate fn guild(mut self, guild_id: GuildId, name: &str) -> Self {
+        self.guilds.insert(guild_id, name.to_string());
+
+        self
+    }
+
+    crate fn user(mut self, user_id: UserId) -> Self {
+        self.users.push(user_id);
+
+        self
+    }
+
+    /// Adds a message to a channel; messages are given oldest first.
+    crate fn message(mut self, channel_id: ChannelId, message_id: MessageId, author_id: UserId) -> Self {
+        self.messages.entry(channel_id).or_default().push(ChannelMessage {
+            id: message_id,
+            author_id
+        });
+
+        self
+    }
+
+    /// The changes made so far, in order.
+    crate fn calls(&self) -> Vec<DiscordCall> {
+        self.calls.lock().unwrap().clone()
+    }
+
+    /// Makes the next change fail; failures queued this way are used up in order.
+    crate fn fail_next(&self, failure: DiscordFailure) {
+        self.failures.lock().unwrap().push(failure);
+    }
+
+    fn record(&self, call: DiscordCall) -> SystemResult<()> {
+        let mut failures = self.failures.lock().unwrap();
+
+        if !failures.is_empty() {
+            return match failures.remove(0) {
+                DiscordFailure::Transient => Err(box CommandError::Discord("Service unavailable.".to_string())),
+                DiscordFailure::UnknownTarget => Err(box UnknownTarget(format!("Unknown target of {:?}.", call)))
+            };
+        }
+
+        self.calls.lock().unwrap().push(call);
+
+        Ok(())
+    }
+}
+
+impl DiscordApi for RecordingDiscordApi {
+    fn guild_name(&self, guild_id: GuildId) -> DiscordFuture<'_, Option<String>> {
+        let name = self.guilds.get(&guild_id).cloned();
+
+        Box::pin(async move { Ok(name) })
+    }
+
+    fn user_exists(&self, user_id: UserId) -> DiscordFuture<'_, bool> {
+        let exists = self.users.contains(&user_id);
+
+        Box::pin(async move { Ok(exists) })
+    }
+
+    fn create_message(&self, channel_id: ChannelId, content: String, reply_to: Option<MessageId>)
+        -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            self.record(DiscordCall::CreateMessage {
+                channel_id,
+                content,
+                reply_to
+            })?;
+
+            Ok(())
+        })
+    }
+
+    fn create_private_channel(&self, user_id: UserId) -> DiscordFuture<'_, ChannelId> {
+        Box::pin(async move {
+            self.record(DiscordCall::CreatePrivateChannel {
+                user_id
+            })?;
+
+            Ok(ChannelId(user_id.0))
+        })
+    }
+
+    fn add_guild_member_role(&self, guild_id: GuildId, user_id: UserId, role_id: RoleId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            self.record(DiscordCall::AddGuildMemberRole {
+                guild_id,
+                user_id,
+                role_id
+            })?;
+
+            Ok(())
+        })
+    }
+
+    fn remove_guild_member_role(&self, guild_id: GuildId, user_id: UserId, role_id: RoleId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            self.record(DiscordCall::RemoveGuildMemberRole {
+                guild_id,
+                user_id,
+                role_id
+            })?;
+
+            Ok(())
+        })
+    }
+
+    fn create_ban(&self, guild_id: GuildId, user_id: UserId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            self.record(DiscordCall::CreateBan {
+                guild_id,
+                user_id
+            })?;
+
+            Ok(())
+        })
+    }
+
+    fn delete_ban(&self, guild_id: GuildId, user_id: UserId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            self.record(DiscordCall::DeleteBan {
+                guild_id,
+                user_id
+            })?;
+
+            Ok(())
+        })
+    }
+
+    fn channel_messages(&self, channel_id: ChannelId, limit: u64) -> DiscordFuture<'_, Vec<ChannelMessage>> {
+        let messages = self.messages.get(&channel_id)
+            .map(|messages| messages.iter().rev().take(limit as usize).copied().collect())
+            .unwrap_or_default();
+
+        Box::pin(async move { Ok(messages) })
+    }
+
+    fn delete_messages(&self, channel_id: ChannelId, message_ids: Vec<MessageId>) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            self.record(DiscordCall::DeleteMessages {
+                channel_id,
+                message_ids
+            })?;
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,118 @@
+use std::error::Error;
+
+use twilight_http::{
+    api_error::{
+        ApiError,
+        ErrorCode,
+        GeneralApiError
+    },
+    Client,
+    Error as HttpError
+};
+
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId,
+        MessageId,
+        RoleId,
+        UserId
+    }
+};
+
+use super::{
+    ChannelMessage,
+    DiscordApi,
+    DiscordFuture,
+    UnknownTarget
+};
+
+/// Turns the errors of Discord about a member, ban or role that does not exist into `UnknownTarget`.
+fn unknown_target(error: HttpError) -> Box<dyn Error + Send + Sync> {
+    match error {
+        HttpError::Response {
+            error: ApiError::General(GeneralApiError {
+                code: ErrorCode::UnknownMember | ErrorCode::UnknownBan | ErrorCode::UnknownRole,
+                message,
+                ..
+            }),
+            ..
+        } => box UnknownTarget(message),
+        error => box error
+    }
+}
+
+impl DiscordApi for Client {
+    fn guild_name(&self, guild_id: GuildId) -> DiscordFuture<'_, Option<String>> {
+        Box::pin(async move {
+            Ok(self.guild(guild_id).await?.map(|guild| guild.name))
+        })
+    }
+
+    fn user_exists(&self, user_id: UserId) -> DiscordFuture<'_, bool> {
+        Box::pin(async move {
+            Ok(self.user(user_id).await?.is_some())
+        })
+    }
+
+    fn create_message(&self, channel_id: ChannelId, content: String, reply_to: Option<MessageId>)
+        -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            let request = self.create_message(channel_id).content(content)?;
+
+            match reply_to {
+                Some(message_id) => request.allowed_mentions().replied_user(false).build().reply(message_id).await?,
+                None => request.await?
+            };
+
+            Ok(())
+        })
+    }
+
+    fn create_private_channel(&self, user_id: UserId) -> DiscordFuture<'_, ChannelId> {
+        Box::pin(async move {
+            Ok(self.create_private_channel(user_id).await?.id)
+        })
+    }
+
+    fn add_guild_member_role(&self, guild_id: GuildId, user_id: UserId, role_id: RoleId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            Ok(self.add_guild_member_role(guild_id, user_id, role_id).await.map_err(unknown_target)?)
+        })
+    }
+
+    fn remove_guild_member_role(&self, guild_id: GuildId, user_id: UserId, role_id: RoleId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            Ok(self.remove_guild_member_role(guild_id, user_id, role_id).await.map_err(unknown_target)?)
+        })
+    }
+
+    fn create_ban(&self, guild_id: GuildId, user_id: UserId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            Ok(self.create_ban(guild_id, user_id).await?)
+        })
+    }
+
+    fn delete_ban(&self, guild_id: GuildId, user_id: UserId) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            Ok(self.delete_ban(guild_id, user_id).await.map_err(unknown_target)?)
+        })
+    }
+
+    fn channel_messages(&self, channel_id: ChannelId, limit: u64) -> DiscordFuture<'_, Vec<ChannelMessage>> {
+        Box::pin(async move {
+            let messages = self.channel_messages(channel_id).limit(limit)?.await?;
+
+            Ok(messages.iter().map(|message| ChannelMessage {
+                id: message.id,
+                author_id: message.author.id
+            }).collect())
+        })
+    }
+
+    fn delete_messages(&self, channel_id: ChannelId, message_ids: Vec<MessageId>) -> DiscordFuture<'_, ()> {
+        Box::pin(async move {
+            Ok(self.delete_messages(channel_id, message_ids).await?)
+        })
+    }
+}
@@ -19,14 +19,25 @@ use twilight_model::{
 use twilight_http::Client;
 
 use crate::{
+    command_system::GuildConfigCache,
     logging::logger::Logger,
     system::{
         model::payload::{
             CommandExecuted,
             CommandFailed,
-            CommandReceived
+            CommandReceived,
+            ConfigChanged,
+            GuildWhitelisted,
+            InfractionCreated,
+            InfractionRemoved,
+            InfractionUpdated,
+            InfractionsCleared,
+            LockdownEnded,
+            LockdownStarted,
+            MemberMuted,
+            MemberUnmuted
         },
-        twilight_http_client_extensions::GetWhitelistedGuilds,
+        stores::WhitelistStore,
         Stopwatch,
         SystemResult
     }
@@ -51,11 +62,12 @@ impl EventHandler {
         Ok(())
     }
 
-    crate async fn guild_create(payload: Box<GuildCreate>, http: Client) -> SystemResult<()> {
+    crate async fn guild_create(payload: Box<GuildCreate>, http: Client, whitelist: &dyn WhitelistStore)
+        -> SystemResult<()> {
         let guild_id = payload.id;
         let guild = http.guild(guild_id).await?;
 
-        match http.clone().get_whitelisted_guilds().await {
+        match whitelist.whitelisted_guilds().await {
             Ok(vector) => {
                 Logger::log_debug(
                     format!("Joined a new guild with ID {}. Checking whether the guild is whitelisted.",
@@ -180,4 +192,97 @@ impl EventHandler {
 
         Ok(())
     }
+
+    crate async fn infraction_created(payload: Box<InfractionCreated>) -> SystemResult<()> {
+        Logger::log_info(
+            format!("Infraction {} ({}) is created for user {} in guild {} by moderator {} with reason '{}'.",
+                    payload.infraction_id, payload.infraction_type, payload.user_id, payload.guild_id,
+                    payload.moderator_id.map_or_else(|| "unknown".to_string(), |moderator_id| moderator_id.to_string()),
+                    payload.reason)
+        );
+
+        Ok(())
+    }
+
+    crate async fn infraction_removed(payload: Box<InfractionRemoved>) -> SystemResult<()> {
+        Logger::log_info(
+            format!("Infraction {} is removed from user {} in guild {}.",
+                    payload.infraction_id, payload.user_id, payload.guild_id)
+        );
+
+        Ok(())
+    }
+
+    crate async fn infraction_updated(payload: Box<InfractionUpdated>) -> SystemResult<()> {
+        Logger::log_info(
+            format!("Infraction {} of user {} in guild {} is updated.",
+                    payload.infraction_id, payload.user_id, payload.guild_id)
+        );
+
+        Ok(())
+    }
+
+    crate async fn infractions_cleared(payload: Box<InfractionsCleared>) -> SystemResult<()> {
+        Logger::log_info(
+            format!("Infractions of user {} in guild {} are cleared.", payload.user_id, payload.guild_id)
+        );
+
+        Ok(())
+    }
+
+    crate async fn member_muted(payload: Box<MemberMuted>) -> SystemResult<()> {
+        match payload.duration {
+            Some(duration) => Logger::log_info(
+                format!("User {} is muted in guild {} for {} seconds.",
+                        payload.user_id, payload.guild_id, duration.as_secs())
+            ),
+            None => Logger::log_info(
+                format!("User {} is muted in guild {}.", payload.user_id, payload.guild_id)
+            )
+        }
+
+        Ok(())
+    }
+
+    crate async fn member_unmuted(payload: Box<MemberUnmuted>) -> SystemResult<()> {
+        Logger::log_info(format!("User {} is unmuted in guild {}.", payload.user_id, payload.guild_id));
+
+        Ok(())
+    }
+
+    crate async fn lockdown_started(payload: Box<LockdownStarted>) -> SystemResult<()> {
+        match payload.channel_id {
+            Some(channel_id) => Logger::log_info(
+                format!("Channel {} in guild {} is locked down.", channel_id, payload.guild_id)
+            ),
+            None => Logger::log_info(format!("Guild {} is locked down.", payload.guild_id))
+        }
+
+        Ok(())
+    }
+
+    crate async fn lockdown_ended(payload: Box<LockdownEnded>) -> SystemResult<()> {
+        match payload.channel_id {
+            Some(channel_id) => Logger::log_info(
+                format!("Channel {} in guild {} is no longer locked down.", channel_id, payload.guild_id)
+            ),
+            None => Logger::log_info(format!("Guild {} is no longer locked down.", payload.guild_id))
+        }
+
+        Ok(())
+    }
+
+    crate async fn guild_whitelisted(payload: Box<GuildWhitelisted>) -> SystemResult<()> {
+        Logger::log_info(format!("Guild {} is whitelisted.", payload.guild_id));
+
+        Ok(())
+    }
+
+    crate async fn config_changed(payload: Box<ConfigChanged>, guild_configs: &GuildConfigCache) -> SystemResult<()> {
+        Logger::log_verbose(format!("The configuration of guild {} is changed.", payload.guild_id));
+
+        guild_configs.invalidate(payload.guild_id);
+
+        Ok(())
+    }
 }
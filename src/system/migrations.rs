@@ -0,0 +1,122 @@
+use chrono::Utc;
+
+use sqlx::{
+    postgres::PgPool,
+    Executor,
+    Row
+};
+
+use crate::logging::logger::Logger;
+
+use crate::system::{
+    database::DatabasePools,
+    SystemResult
+};
+
+/// A change to the schema of a database, which is applied once.
+crate struct Migration {
+    crate version: i64,
+    crate description: &'static str,
+    crate sql: &'static str
+}
+
+/// The migrations of the infractions database, in the order they are applied; a migration must never be changed once
+/// it is released, as databases that applied it would not apply it again.
+crate const INFRACTIONS_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Create the infractions table",
+        sql: include_str!("../../migrations/infractions/0001_create_infractions.sql")
+    },
+    Migration {
+        version: 2,
+        description: "Import the infractions from the tables of each member",
+        sql: include_str!("../../migrations/infractions/0002_import_per_user_infraction_tables.sql")
+    },
+    Migration {
+        version: 3,
+        description: "Create the scheduled actions table",
+        sql: include_str!("../../migrations/infractions/0003_create_scheduled_actions.sql")
+    }
+];
+
+/// The migrations of the guilds database, in the order they are applied.
+crate const GUILDS_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Create the error reports table",
+        sql: include_str!("../../migrations/guilds/0001_create_error_reports.sql")
+    }
+];
+
+/// Applies the migrations of each database that were not yet applied, returning how many were.
+crate async fn migrate_databases(pools: &DatabasePools) -> SystemResult<usize> {
+    Ok(apply_migrations(&pools.guilds, GUILDS_MIGRATIONS).await?
+        + apply_migrations(&pools.guild_infractions, INFRACTIONS_MIGRATIONS).await?)
+}
+
+async fn apply_migrations(connection: &PgPool, migrations: &[Migration]) -> SystemResult<usize> {
+    sqlx::query(
+        // language=SQL
+        "CREATE TABLE IF NOT EXISTS schema_migrations (version BIGINT PRIMARY KEY, description TEXT NOT NULL, applied_at TIMESTAMPTZ NOT NULL); --"
+    )
+        .execute(connection)
+        .await?;
+
+    let applied_version: i64 = sqlx::query(
+        // language=SQL
+        "SELECT COALESCE(MAX(version), 0) AS version FROM schema_migrations; --"
+    )
+        .fetch_one(connection)
+        .await?
+        .get("version");
+
+    let mut applied = 0;
+
+    for migration in migrations.iter().filter(|migration| migration.version > applied_version) {
+        Logger::log_debug(format!("Applying migration {}: {}.", migration.version, migration.description));
+
+        // Each migration is applied along with its record in one transaction, so a failed migration is retried in
+        // full at the next startup.
+        let mut transaction = connection.begin().await?;
+
+        transaction.execute(migration.sql).await?;
+
+        sqlx::query(
+            // language=SQL
+            "INSERT INTO schema_migrations (version, description, applied_at) VALUES ($1, $2, $3); --"
+        )
+            .bind(migration.version)
+            .bind(migration.description)
+            .bind(Utc::now())
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        applied += 1;
+    }
+
+    Ok(applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Migration,
+        GUILDS_MIGRATIONS,
+        INFRACTIONS_MIGRATIONS
+    };
+
+    fn assert_versions_increase_from_one(migrations: &[Migration]) {
+        let versions = migrations.iter().map(|migration| migration.version).collect::<Vec<_>>();
+
+        assert_eq!(versions, (1..=migrations.len() as i64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn versions_increase_from_one() {
+        assert_versions_increase_from_one(GUILDS_MIGRATIONS);
+        assert_versions_increase_from_one(INFRACTIONS_MIGRATIONS);
+    }
+}
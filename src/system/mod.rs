@@ -23,9 +23,14 @@ use twilight_model::{
 };
 
 crate mod bot_configuration;
+crate mod database;
+crate mod discord_api;
 crate mod event_handler;
 crate mod internal_bot_error;
+crate mod migrations;
 crate mod model;
+crate mod scheduler;
+crate mod stores;
 crate mod terminal;
 crate mod twilight_http_client_extensions;
 crate mod twilight_id_extensions;
@@ -0,0 +1,55 @@
+use std::{
+    error::Error
+};
+
+use chrono::{
+    DateTime,
+    Utc
+};
+
+use twilight_model::{
+    channel::Message,
+    id::{
+        ChannelId,
+        GuildId,
+        UserId
+    }
+};
+
+/// An internal error raised by a command, stored under the code shown to the user so that support can look it up.
+#[derive(Debug, Clone)]
+crate struct ErrorReport {
+    crate code: String,
+    crate reported_at: DateTime<Utc>,
+    crate command: String,
+    crate arguments: String,
+    crate guild_id: Option<GuildId>,
+    crate channel_id: ChannelId,
+    crate user_id: UserId,
+    /// The error followed by each of its sources.
+    crate error_chain: Vec<String>
+}
+
+impl ErrorReport {
+    crate fn new(code: String, command: String, arguments: String, message: &Message,
+                 error: &(dyn Error + 'static)) -> Self {
+        let mut error_chain = vec![error.to_string()];
+        let mut source = error.source();
+
+        while let Some(error) = source {
+            error_chain.push(error.to_string());
+            source = error.source();
+        }
+
+        Self {
+            code,
+            reported_at: Utc::now(),
+            command,
+            arguments,
+            guild_id: message.guild_id,
+            channel_id: message.channel_id,
+            user_id: message.author.id,
+            error_chain
+        }
+    }
+}
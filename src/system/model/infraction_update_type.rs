@@ -1,6 +1,14 @@
+use twilight_model::id::UserId;
+
 #[derive(Clone)]
 crate enum InfractionUpdateType {
     Reason {
         new_reason: String
+    },
+
+    /// Marks the infraction as no longer standing, such as when a muted member is unmuted early.
+    Revoke {
+        revoked_by: UserId,
+        reason: String
     }
 }
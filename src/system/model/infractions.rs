@@ -4,6 +4,13 @@ use std::fmt::{
     Result
 };
 
+use chrono::{
+    DateTime,
+    Utc
+};
+
+use twilight_model::id::UserId;
+
 #[derive(Debug, Copy, Clone)]
 crate enum InfractionType {
     Ban,
@@ -29,19 +36,98 @@ impl Display for InfractionType {
     }
 }
 
+impl InfractionType {
+    /// Reads a type back from the name it is stored under.
+    crate fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "warning" => Some(Self::Warning),
+            "mute" => Some(Self::Mute),
+            "unmute" => Some(Self::Unmute),
+            "temp-mute" => Some(Self::TemporaryMute),
+            "kick" => Some(Self::Kick),
+            "ban" => Some(Self::Ban),
+            "unban" => Some(Self::Unban),
+            _ => None
+        }
+    }
+}
+
+/// Who revoked an infraction, when, and why.
+#[derive(Debug, Clone)]
+crate struct InfractionRevocation {
+    pub revoked_by: UserId,
+    pub revoked_at: DateTime<Utc>,
+    pub reason: String
+}
+
 #[derive(Debug, Clone)]
 crate struct Infraction {
+    /// The case number of the infraction in its guild, such as `12`; infractions recorded before case numbers were
+    /// have the hash they were identified by instead. It is empty until the infraction is stored.
     pub infraction_id: String,
     pub reason: String,
-    pub infraction_type: InfractionType
+    pub infraction_type: InfractionType,
+    /// The moderator who gave the infraction; it is unknown for infractions recorded before moderators were.
+    pub moderator_id: Option<UserId>,
+    /// When the infraction was given; it is unknown for infractions recorded before creation times were.
+    pub created_at: Option<DateTime<Utc>>,
+    /// When a temporary infraction, such as a temporary mute, ends.
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Whether the infraction still stands, which is no longer the case once it is revoked.
+    pub active: bool,
+    pub revocation: Option<InfractionRevocation>
 }
 
 impl Infraction {
-    crate fn new(infraction_id: String, reason: String, infraction_type: InfractionType) -> Self {
+    /// An active infraction given by a moderator now, which is given a case number when it is stored.
+    crate fn new(reason: String, infraction_type: InfractionType, moderator_id: UserId) -> Self {
         Self {
-            infraction_id,
+            infraction_id: String::new(),
             reason,
-            infraction_type
+            infraction_type,
+            moderator_id: Some(moderator_id),
+            created_at: Some(Utc::now()),
+            expires_at: None,
+            active: true,
+            revocation: None
+        }
+    }
+
+    /// Makes the infraction end at a given time.
+    crate fn expiring_at(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.expires_at = Some(expires_at);
+
+        self
+    }
+
+    /// The case number of the infraction, unless it was recorded before case numbers were.
+    crate fn case_number(&self) -> Option<u64> {
+        self.infraction_id.parse().ok()
+    }
+
+    /// The ID of the infraction as it is shown to users: `#12` for case numbers, and the hash for older infractions.
+    crate fn display_id(&self) -> String {
+        match self.case_number() {
+            Some(case_number) => format!("#{}", case_number),
+            None => self.infraction_id.clone()
+        }
+    }
+
+    /// The stored ID of an infraction given by a user, who may write case numbers as `#12` or `12`.
+    crate fn lookup_id(input: &str) -> String {
+        input.trim().trim_start_matches('#').to_lowercase()
+    }
+
+    /// Whether the infraction is active, revoked, or has ended on its own.
+    crate fn status(&self) -> &'static str {
+        if !self.active {
+            "revoked"
+        }
+        else if matches!(self.expires_at, Some(expires_at) if expires_at <= Utc::now()) {
+            "expired"
+        }
+        else {
+            "active"
         }
     }
 }
@@ -1,4 +1,6 @@
+crate mod error_report;
 crate mod infractions;
 crate mod infraction_update_type;
 crate mod payload;
 crate mod permissions;
+crate mod scheduled_action;
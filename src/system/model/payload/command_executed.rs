@@ -2,7 +2,7 @@ use crate::command_system::CommandContext;
 
 #[derive(Clone)]
 crate struct CommandExecuted {
-    crate command: &'static str,
+    crate command: String,
     crate guild_name: String,
     crate context: CommandContext<'static>
 }
@@ -4,6 +4,6 @@ use std::{
 
 #[derive(Clone)]
 crate struct CommandFailed {
-    crate command: &'static str,
+    crate command: String,
     crate error: String
 }
@@ -0,0 +1,8 @@
+use twilight_model::{
+    id::GuildId
+};
+
+#[derive(Clone)]
+crate struct ConfigChanged {
+    crate guild_id: GuildId
+}
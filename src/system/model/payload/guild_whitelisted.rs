@@ -0,0 +1,8 @@
+use twilight_model::{
+    id::GuildId
+};
+
+#[derive(Clone)]
+crate struct GuildWhitelisted {
+    crate guild_id: GuildId
+}
@@ -0,0 +1,18 @@
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+use crate::system::model::infractions::InfractionType;
+
+#[derive(Clone)]
+crate struct InfractionCreated {
+    crate guild_id: GuildId,
+    crate user_id: UserId,
+    crate moderator_id: Option<UserId>,
+    crate infraction_id: String,
+    crate reason: String,
+    crate infraction_type: InfractionType
+}
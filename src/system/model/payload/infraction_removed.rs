@@ -0,0 +1,13 @@
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+#[derive(Clone)]
+crate struct InfractionRemoved {
+    crate guild_id: GuildId,
+    crate user_id: UserId,
+    crate infraction_id: String
+}
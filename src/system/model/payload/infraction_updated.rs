@@ -0,0 +1,16 @@
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+use crate::system::model::infraction_update_type::InfractionUpdateType;
+
+#[derive(Clone)]
+crate struct InfractionUpdated {
+    crate guild_id: GuildId,
+    crate user_id: UserId,
+    crate infraction_id: String,
+    crate update_type: InfractionUpdateType
+}
@@ -0,0 +1,12 @@
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+#[derive(Clone)]
+crate struct InfractionsCleared {
+    crate guild_id: GuildId,
+    crate user_id: UserId
+}
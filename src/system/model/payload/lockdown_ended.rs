@@ -0,0 +1,13 @@
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId
+    }
+};
+
+#[derive(Clone)]
+crate struct LockdownEnded {
+    crate guild_id: GuildId,
+    /// The unlocked channel, or `None` if the whole guild is unlocked.
+    crate channel_id: Option<ChannelId>
+}
@@ -0,0 +1,13 @@
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId
+    }
+};
+
+#[derive(Clone)]
+crate struct LockdownStarted {
+    crate guild_id: GuildId,
+    /// The locked channel, or `None` if the whole guild is locked.
+    crate channel_id: Option<ChannelId>
+}
@@ -0,0 +1,18 @@
+use std::{
+    time::Duration
+};
+
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+#[derive(Clone)]
+crate struct MemberMuted {
+    crate guild_id: GuildId,
+    crate user_id: UserId,
+    /// How long the member is muted for, if the mute is temporary.
+    crate duration: Option<Duration>
+}
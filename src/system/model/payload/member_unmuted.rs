@@ -0,0 +1,12 @@
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+#[derive(Clone)]
+crate struct MemberUnmuted {
+    crate guild_id: GuildId,
+    crate user_id: UserId
+}
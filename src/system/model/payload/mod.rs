@@ -1,7 +1,27 @@
 mod command_executed;
 mod command_failed;
 mod command_received;
+mod config_changed;
+mod guild_whitelisted;
+mod infraction_created;
+mod infraction_removed;
+mod infraction_updated;
+mod infractions_cleared;
+mod lockdown_ended;
+mod lockdown_started;
+mod member_muted;
+mod member_unmuted;
 
 crate use command_executed::CommandExecuted;
 crate use command_failed::CommandFailed;
 crate use command_received::CommandReceived;
+crate use config_changed::ConfigChanged;
+crate use guild_whitelisted::GuildWhitelisted;
+crate use infraction_created::InfractionCreated;
+crate use infraction_removed::InfractionRemoved;
+crate use infraction_updated::InfractionUpdated;
+crate use infractions_cleared::InfractionsCleared;
+crate use lockdown_ended::LockdownEnded;
+crate use lockdown_started::LockdownStarted;
+crate use member_muted::MemberMuted;
+crate use member_unmuted::MemberUnmuted;
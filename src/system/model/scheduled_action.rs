@@ -0,0 +1,97 @@
+use chrono::{
+    DateTime,
+    Utc
+};
+
+use twilight_model::{
+    id::{
+        GuildId,
+        RoleId,
+        UserId
+    }
+};
+
+/// What is done to a member when a scheduled action is due.
+#[derive(Debug, Copy, Clone, PartialEq)]
+crate enum ScheduledActionKind {
+    /// Ends a temporary mute by removing the muted role, and giving back the role the mute removed, if any.
+    Unmute {
+        muted_role_id: RoleId,
+        role_to_restore: Option<RoleId>
+    },
+
+    /// Ends a temporary ban.
+    Unban,
+
+    /// Removes a role given for a while.
+    RemoveRole {
+        role_id: RoleId
+    }
+}
+
+impl ScheduledActionKind {
+    /// The name the kind is stored under in the database.
+    crate fn name(&self) -> &'static str {
+        match self {
+            Self::Unmute { .. } => "unmute",
+            Self::Unban => "unban",
+            Self::RemoveRole { .. } => "remove-role"
+        }
+    }
+
+    /// The role that the action removes, if any.
+    crate fn role_id(&self) -> Option<RoleId> {
+        match *self {
+            Self::Unmute { muted_role_id, .. } => Some(muted_role_id),
+            Self::Unban => None,
+            Self::RemoveRole { role_id } => Some(role_id)
+        }
+    }
+
+    /// The role that the action gives back, if any.
+    crate fn role_to_restore(&self) -> Option<RoleId> {
+        match *self {
+            Self::Unmute { role_to_restore, .. } => role_to_restore,
+            _ => None
+        }
+    }
+
+    /// Reads a kind back from its stored name and roles.
+    crate fn from_parts(name: &str, role_id: Option<RoleId>, role_to_restore: Option<RoleId>) -> Option<Self> {
+        match (name, role_id) {
+            ("unmute", Some(muted_role_id)) => Some(Self::Unmute {
+                muted_role_id,
+                role_to_restore
+            }),
+            ("unban", _) => Some(Self::Unban),
+            ("remove-role", Some(role_id)) => Some(Self::RemoveRole {
+                role_id
+            }),
+            _ => None
+        }
+    }
+}
+
+/// An action on a member that is due at a later time, such as the end of a temporary mute, which is stored so that
+/// it still happens if the bot restarts in the meantime.
+#[derive(Debug, Clone)]
+crate struct ScheduledAction {
+    /// The ID the action is stored under; it is 0 until the action is stored.
+    crate id: u64,
+    crate guild_id: GuildId,
+    crate user_id: UserId,
+    crate kind: ScheduledActionKind,
+    crate due_at: DateTime<Utc>
+}
+
+impl ScheduledAction {
+    crate fn new(guild_id: GuildId, user_id: UserId, kind: ScheduledActionKind, due_at: DateTime<Utc>) -> Self {
+        Self {
+            id: 0,
+            guild_id,
+            user_id,
+            kind,
+            due_at
+        }
+    }
+}
@@ -0,0 +1,397 @@
+use std::{
+    sync::Arc,
+    time::Duration
+};
+
+use chrono::Utc;
+
+use dashmap::DashMap;
+
+use tokio::task::JoinHandle;
+
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+use crate::command_system::events::{
+    emitter::CommandEventEmitter,
+    events::SystemEvent
+};
+
+use crate::logging::logger::Logger;
+
+use crate::system::{
+    discord_api::{
+        DiscordApi,
+        UnknownTarget
+    },
+    model::{
+        payload::MemberUnmuted,
+        scheduled_action::{
+            ScheduledAction,
+            ScheduledActionKind
+        }
+    },
+    stores::ScheduledActionStore,
+    SystemResult
+};
+
+/// How long the scheduler waits before retrying an action that failed for the first time; the wait doubles with every
+/// further failure, up to `MAX_RETRY_DELAY`.
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// The longest the scheduler waits before retrying an action that failed.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(3600);
+
+/// Runs actions on members when they are due, such as ending temporary mutes and bans.
+///
+/// Actions are stored until they are done, cancelled, or can never be done, and the pending ones are loaded again at
+/// startup, so that a restart does not leave anyone muted or banned forever. An action that fails otherwise, such as
+/// because Discord is unavailable, is retried with a growing delay.
+#[derive(Clone)]
+crate struct Scheduler {
+    discord: Arc<dyn DiscordApi>,
+    store: Arc<dyn ScheduledActionStore>,
+    emitter: CommandEventEmitter,
+    timers: Arc<DashMap<u64, Timer>>
+}
+
+/// A pending action, along with the task waiting for it to be due.
+struct Timer {
+    action: ScheduledAction,
+    handle: JoinHandle<()>
+}
+
+impl Scheduler {
+    crate fn new(discord: Arc<dyn DiscordApi>, store: Arc<dyn ScheduledActionStore>, emitter: CommandEventEmitter)
+        -> Self {
+        Self {
+            discord,
+            store,
+            emitter,
+            timers: Arc::new(DashMap::new())
+        }
+    }
+
+    /// Loads the actions that were pending when the bot stopped; those that became due in the meantime are run
+    /// immediately.
+    crate async fn load(&self) -> SystemResult<usize> {
+        let actions = self.store.scheduled_actions().await?;
+        let count = actions.len();
+
+        for action in actions {
+            self.start(action);
+        }
+
+        Ok(count)
+    }
+
+    /// Stores an action and runs it when it is due, returning the ID it is stored under.
+    crate async fn schedule(&self, action: ScheduledAction) -> SystemResult<u64> {
+        let id = self.store.add_scheduled_action(action.clone()).await?;
+
+        self.start(ScheduledAction {
+            id,
+            ..action
+        });
+
+        Ok(id)
+    }
+
+    /// Cancels the pending actions on a member that match the predicate, such as the end of a temporary mute when
+    /// the member is unmuted early, returning how many were cancelled.
+    crate async fn cancel(&self, guild_id: GuildId, user_id: UserId, predicate: impl Fn(&ScheduledActionKind) -> bool)
+        -> SystemResult<usize> {
+        let ids = self.timers.iter()
+            .filter(|timer| timer.action.guild_id == guild_id && timer.action.user_id == user_id
+                && predicate(&timer.action.kind))
+            .map(|timer| *timer.key())
+            .collect::<Vec<_>>();
+
+        for id in &ids {
+            if let Some((_, timer)) = self.timers.remove(id) {
+                timer.handle.abort();
+            }
+
+            self.store.remove_scheduled_action(*id).await?;
+        }
+
+        Ok(ids.len())
+    }
+
+    /// The actions that are waiting to be due.
+    #[cfg(test)]
+    crate fn pending(&self) -> Vec<ScheduledAction> {
+        self.timers.iter().map(|timer| timer.action.clone()).collect()
+    }
+
+    fn start(&self, action: ScheduledAction) {
+        let scheduler = self.clone();
+        let id = action.id;
+
+        // The timer is inserted while its entry is locked, so that it cannot finish and remove itself before it is
+        // inserted.
+        self.timers.entry(id).or_insert_with(|| {
+            let delay = (action.due_at - Utc::now()).to_std().unwrap_or_default();
+
+            Timer {
+                action: action.clone(),
+                handle: tokio::spawn(async move {
+                    tokio::time::sleep(delay).await;
+
+                    let mut failures = 0;
+
+                    while let Err(error) = scheduler.execute(&action).await {
+                        failures += 1;
+
+                        let wait = retry_delay(failures);
+
+                        Logger::log_error(
+                            format!("Could not run scheduled action `{}` for user {}; retrying in {} seconds. Error: {}",
+                                    action.kind.name(), action.user_id, wait.as_secs(), error));
+
+                        tokio::time::sleep(wait).await;
+                    }
+
+                    scheduler.timers.remove(&action.id);
+                })
+            }
+        });
+    }
+
+    /// Runs an action, and removes it from the store once it is done or can never be done, such as because the
+    /// member left the guild; an action that failed otherwise is kept in the store and its error returned, so that it
+    /// is retried.
+    crate async fn execute(&self, action: &ScheduledAction) -> SystemResult<()> {
+        match self.run(action).await {
+            Err(error) if error.is::<UnknownTarget>() => Logger::log_warning(
+                format!("Dropping scheduled action `{}` for user {}, which can no longer be run. Error: {}",
+                        action.kind.name(), action.user_id, error)),
+            result => result?
+        }
+
+        self.store.remove_scheduled_action(action.id).await
+    }
+
+    async fn run(&self, action: &ScheduledAction) -> SystemResult<()> {
+        let guild_id = action.guild_id;
+        let user_id = action.user_id;
+
+        match action.kind {
+            ScheduledActionKind::Unmute { muted_role_id, role_to_restore } => {
+                self.discord.remove_guild_member_role(guild_id, user_id, muted_role_id).await?;
+
+                if let Some(role_id) = role_to_restore {
+                    self.discord.add_guild_member_role(guild_id, user_id, role_id).await?;
+                }
+
+                self.emitter.event(SystemEvent::MemberUnmuted(box MemberUnmuted {
+                    guild_id,
+                    user_id
+                })).await;
+            },
+            ScheduledActionKind::Unban => {
+                self.discord.delete_ban(guild_id, user_id).await?;
+            },
+            ScheduledActionKind::RemoveRole { role_id } => {
+                self.discord.remove_guild_member_role(guild_id, user_id, role_id).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// How long to wait before retrying an action that failed a number of times in a row.
+fn retry_delay(failures: u32) -> Duration {
+    RETRY_DELAY.checked_mul(1 << failures.saturating_sub(1).min(16))
+        .map_or(MAX_RETRY_DELAY, |delay| delay.min(MAX_RETRY_DELAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        time::Duration as StdDuration
+    };
+
+    use chrono::{
+        Duration,
+        Utc
+    };
+
+    use twilight_model::id::{
+        GuildId,
+        RoleId,
+        UserId
+    };
+
+    use crate::command_system::events::{
+        emitter::CommandEventEmitter,
+        listener::Listeners
+    };
+
+    use crate::system::{
+        discord_api::{
+            DiscordCall,
+            DiscordFailure,
+            RecordingDiscordApi
+        },
+        model::scheduled_action::{
+            ScheduledAction,
+            ScheduledActionKind
+        },
+        stores::{
+            InMemoryScheduledActionStore,
+            ScheduledActionStore
+        }
+    };
+
+    use super::{
+        Scheduler,
+        RETRY_DELAY
+    };
+
+    const GUILD_ID: GuildId = GuildId(1);
+    const USER_ID: UserId = UserId(2);
+    const MUTED_ROLE_ID: RoleId = RoleId(3);
+    const MEMBER_ROLE_ID: RoleId = RoleId(4);
+
+    fn scheduler(discord: &RecordingDiscordApi, store: &InMemoryScheduledActionStore) -> Scheduler {
+        Scheduler::new(Arc::new(discord.clone()), Arc::new(store.clone()), CommandEventEmitter::new(Listeners::default()))
+    }
+
+    fn unmute(due_in: Duration) -> ScheduledAction {
+        ScheduledAction::new(GUILD_ID, USER_ID, ScheduledActionKind::Unmute {
+            muted_role_id: MUTED_ROLE_ID,
+            role_to_restore: Some(MEMBER_ROLE_ID)
+        }, Utc::now() + due_in)
+    }
+
+    #[tokio::test]
+    async fn runs_and_forgets_an_action_when_due() {
+        let discord = RecordingDiscordApi::new();
+        let store = InMemoryScheduledActionStore::new();
+        let scheduler = scheduler(&discord, &store);
+
+        let id = scheduler.schedule(unmute(Duration::hours(1))).await.unwrap();
+        let action = scheduler.pending().remove(0);
+
+        assert_eq!(action.id, id);
+
+        scheduler.execute(&action).await.unwrap();
+
+        assert_eq!(discord.calls(), vec![
+            DiscordCall::RemoveGuildMemberRole {
+                guild_id: GUILD_ID,
+                user_id: USER_ID,
+                role_id: MUTED_ROLE_ID
+            },
+            DiscordCall::AddGuildMemberRole {
+                guild_id: GUILD_ID,
+                user_id: USER_ID,
+                role_id: MEMBER_ROLE_ID
+            }
+        ]);
+        assert!(store.scheduled_actions().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn loads_pending_actions_and_runs_overdue_ones() {
+        tokio::time::pause();
+
+        let discord = RecordingDiscordApi::new();
+        let store = InMemoryScheduledActionStore::new();
+
+        store.add_scheduled_action(unmute(Duration::hours(1))).await.unwrap();
+        store.add_scheduled_action(ScheduledAction::new(GUILD_ID, USER_ID, ScheduledActionKind::Unban,
+                                                        Utc::now() - Duration::hours(1))).await.unwrap();
+
+        let scheduler = scheduler(&discord, &store);
+
+        assert_eq!(scheduler.load().await.unwrap(), 2);
+
+        // The clock is paused, so this only lets the overdue action run.
+        tokio::time::sleep(StdDuration::from_secs(1)).await;
+
+        assert_eq!(discord.calls(), vec![
+            DiscordCall::DeleteBan {
+                guild_id: GUILD_ID,
+                user_id: USER_ID
+            }
+        ]);
+        assert_eq!(scheduler.pending().len(), 1);
+        assert_eq!(store.scheduled_actions().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn keeps_and_retries_an_action_that_failed() {
+        tokio::time::pause();
+
+        let discord = RecordingDiscordApi::new();
+        let store = InMemoryScheduledActionStore::new();
+        let scheduler = scheduler(&discord, &store);
+
+        discord.fail_next(DiscordFailure::Transient);
+
+        scheduler.schedule(ScheduledAction::new(GUILD_ID, USER_ID, ScheduledActionKind::Unban,
+                                                Utc::now() - Duration::hours(1))).await.unwrap();
+
+        tokio::time::sleep(StdDuration::from_secs(1)).await;
+
+        assert!(discord.calls().is_empty());
+        assert_eq!(scheduler.pending().len(), 1);
+        assert_eq!(store.scheduled_actions().await.unwrap().len(), 1);
+
+        tokio::time::sleep(RETRY_DELAY).await;
+
+        assert_eq!(discord.calls(), vec![
+            DiscordCall::DeleteBan {
+                guild_id: GUILD_ID,
+                user_id: USER_ID
+            }
+        ]);
+        assert!(scheduler.pending().is_empty());
+        assert!(store.scheduled_actions().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn forgets_an_action_that_can_no_longer_be_run() {
+        let discord = RecordingDiscordApi::new();
+        let store = InMemoryScheduledActionStore::new();
+        let scheduler = scheduler(&discord, &store);
+
+        scheduler.schedule(unmute(Duration::hours(1))).await.unwrap();
+        let action = scheduler.pending().remove(0);
+
+        discord.fail_next(DiscordFailure::UnknownTarget);
+
+        scheduler.execute(&action).await.unwrap();
+
+        assert!(store.scheduled_actions().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancels_matching_actions() {
+        let discord = RecordingDiscordApi::new();
+        let store = InMemoryScheduledActionStore::new();
+        let scheduler = scheduler(&discord, &store);
+
+        scheduler.schedule(unmute(Duration::hours(1))).await.unwrap();
+        scheduler.schedule(ScheduledAction::new(GUILD_ID, USER_ID, ScheduledActionKind::Unban,
+                                                Utc::now() + Duration::hours(1))).await.unwrap();
+
+        let cancelled = scheduler
+            .cancel(GUILD_ID, USER_ID, |kind| matches!(kind, ScheduledActionKind::Unmute { .. }))
+            .await
+            .unwrap();
+
+        assert_eq!(cancelled, 1);
+        assert!(matches!(scheduler.pending()[..], [ScheduledAction { kind: ScheduledActionKind::Unban, .. }]));
+        assert_eq!(store.scheduled_actions().await.unwrap().len(), 1);
+        assert!(discord.calls().is_empty());
+    }
+}
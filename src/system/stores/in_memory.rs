@@ -0,0 +1,270 @@
+use std::{
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering
+        },
+        Arc
+    }
+};
+
+use chrono::Utc;
+
+use dashmap::{
+    DashMap,
+    DashSet
+};
+
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+use crate::command_system::CommandError;
+
+use crate::system::model::{
+    error_report::ErrorReport,
+    infraction_update_type::InfractionUpdateType,
+    infractions::{
+        Infraction,
+        InfractionRevocation
+    },
+    scheduled_action::ScheduledAction
+};
+
+use super::{
+    ConfigStore,
+    ErrorReportStore,
+    InfractionStore,
+    ScheduledActionStore,
+    StoreFuture,
+    WhitelistStore
+};
+
+/// Infractions kept in memory, in the order they were added, and numbered from 1 in each guild.
+#[derive(Clone, Default)]
+crate struct InMemoryInfractionStore {
+    infractions: Arc<DashMap<(GuildId, UserId), Vec<Infraction>>>,
+    case_numbers: Arc<DashMap<GuildId, u64>>
+}
+
+impl InMemoryInfractionStore {
+    crate fn new() -> Self {
+        Self::default()
+    }
+
+    /// The infractions of a member, without going through a future.
+    crate fn infractions_of(&self, guild_id: GuildId, user_id: UserId) -> Vec<Infraction> {
+        self.infractions.get(&(guild_id, user_id)).map(|infractions| infractions.clone()).unwrap_or_default()
+    }
+}
+
+impl InfractionStore for InMemoryInfractionStore {
+    fn add_infraction(&self, guild_id: GuildId, user_id: UserId, mut infraction: Infraction) -> StoreFuture<'_, u64> {
+        let case_number = *self.case_numbers.entry(guild_id)
+            .and_modify(|case_number| *case_number += 1)
+            .or_insert(1);
+
+        infraction.infraction_id = case_number.to_string();
+        self.infractions.entry((guild_id, user_id)).or_default().push(infraction);
+
+        Box::pin(async move { Ok(case_number) })
+    }
+
+    fn user_infractions(&self, guild_id: GuildId, user_id: UserId) -> StoreFuture<'_, Vec<Infraction>> {
+        let infractions = self.infractions_of(guild_id, user_id);
+
+        Box::pin(async move { Ok(infractions) })
+    }
+
+    fn guild_infractions(&self, guild_id: GuildId) -> StoreFuture<'_, DashMap<UserId, Vec<Infraction>>> {
+        let infractions = self.infractions.iter()
+            .filter(|entry| entry.key().0 == guild_id)
+            .map(|entry| (entry.key().1, entry.value().clone()))
+            .collect();
+
+        Box::pin(async move { Ok(infractions) })
+    }
+
+    fn update_infraction(&self, guild_id: GuildId, user_id: UserId, infraction_id: String,
+                         update_type: InfractionUpdateType) -> StoreFuture<'_, Infraction> {
+        let infraction_id = Infraction::lookup_id(&infraction_id);
+        let infraction_old = self.infractions.get_mut(&(guild_id, user_id)).and_then(|mut infractions| {
+            let infraction = infractions.iter_mut().find(|infraction| infraction.infraction_id == infraction_id)?;
+            let infraction_old = infraction.clone();
+
+            match update_type {
+                InfractionUpdateType::Reason { new_reason } => infraction.reason = new_reason,
+                InfractionUpdateType::Revoke { revoked_by, reason } => {
+                    infraction.active = false;
+                    infraction.revocation = Some(InfractionRevocation {
+                        revoked_by,
+                        revoked_at: Utc::now(),
+                        reason
+                    });
+                }
+            }
+
+            Some(infraction_old)
+        });
+
+        Box::pin(async move {
+            Ok(infraction_old.ok_or_else(|| {
+                CommandError::User(format!("No infraction #{} found for that user.", infraction_id))
+            })?)
+        })
+    }
+
+    fn remove_infraction(&self, guild_id: GuildId, user_id: UserId, infraction_id: String) -> StoreFuture<'_, ()> {
+        let infraction_id = Infraction::lookup_id(&infraction_id);
+
+        let removed = self.infractions.get_mut(&(guild_id, user_id)).map_or(false, |mut infractions| {
+            let count = infractions.len();
+            infractions.retain(|infraction| infraction.infraction_id != infraction_id);
+
+            infractions.len() < count
+        });
+
+        Box::pin(async move {
+            if !removed {
+                return Err(box CommandError::User(format!("No infraction #{} found for that user.", infraction_id)));
+            }
+
+            Ok(())
+        })
+    }
+
+    fn clear_infractions(&self, guild_id: GuildId, user_id: UserId) -> StoreFuture<'_, ()> {
+        self.infractions.remove(&(guild_id, user_id));
+
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Scheduled actions kept in memory, stored under increasing IDs starting from 1.
+#[derive(Clone, Default)]
+crate struct InMemoryScheduledActionStore {
+    next_id: Arc<AtomicU64>,
+    actions: Arc<DashMap<u64, ScheduledAction>>
+}
+
+impl InMemoryScheduledActionStore {
+    crate fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ScheduledActionStore for InMemoryScheduledActionStore {
+    fn add_scheduled_action(&self, action: ScheduledAction) -> StoreFuture<'_, u64> {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+
+        self.actions.insert(id, ScheduledAction {
+            id,
+            ..action
+        });
+
+        Box::pin(async move { Ok(id) })
+    }
+
+    fn scheduled_actions(&self) -> StoreFuture<'_, Vec<ScheduledAction>> {
+        let actions = self.actions.iter().map(|action| action.value().clone()).collect();
+
+        Box::pin(async move { Ok(actions) })
+    }
+
+    fn remove_scheduled_action(&self, id: u64) -> StoreFuture<'_, ()> {
+        self.actions.remove(&id);
+
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Guild configurations kept in memory.
+#[derive(Clone, Default)]
+crate struct InMemoryConfigStore {
+    configs: Arc<DashMap<GuildId, String>>
+}
+
+impl InMemoryConfigStore {
+    crate fn new() -> Self {
+        Self::default()
+    }
+
+    crate fn config(self, guild_id: GuildId, config: &str) -> Self {
+        self.configs.insert(guild_id, config.to_string());
+
+        self
+    }
+}
+
+impl ConfigStore for InMemoryConfigStore {
+    fn guild_configuration(&self, guild_id: GuildId) -> StoreFuture<'_, String> {
+        let config = self.configs.get(&guild_id).map(|config| config.clone());
+
+        Box::pin(async move {
+            Ok(config.ok_or_else(|| CommandError::Database("Guild is not configured.".to_string()))?)
+        })
+    }
+
+    fn set_guild_configuration(&self, guild_id: GuildId, config: String) -> StoreFuture<'_, ()> {
+        self.configs.insert(guild_id, config);
+
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Whitelisted guilds kept in memory, listed in the order of their IDs.
+#[derive(Clone, Default)]
+crate struct InMemoryWhitelistStore {
+    guilds: Arc<DashSet<GuildId>>
+}
+
+impl InMemoryWhitelistStore {
+    crate fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl WhitelistStore for InMemoryWhitelistStore {
+    fn whitelisted_guilds(&self) -> StoreFuture<'_, Vec<GuildId>> {
+        let mut guilds = self.guilds.iter().map(|guild_id| *guild_id).collect::<Vec<_>>();
+        guilds.sort();
+
+        Box::pin(async move { Ok(guilds) })
+    }
+
+    fn initialize_guild(&self, guild_id: GuildId) -> StoreFuture<'_, ()> {
+        self.guilds.insert(guild_id);
+
+        Box::pin(async { Ok(()) })
+    }
+}
+
+/// Error reports kept in memory.
+#[derive(Clone, Default)]
+crate struct InMemoryErrorReportStore {
+    reports: Arc<DashMap<String, ErrorReport>>
+}
+
+impl InMemoryErrorReportStore {
+    crate fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ErrorReportStore for InMemoryErrorReportStore {
+    fn add_error_report(&self, report: ErrorReport) -> StoreFuture<'_, ()> {
+        // The same error raised by the same message has the same code, so it is only stored once.
+        self.reports.entry(report.code.clone()).or_insert(report);
+
+        Box::pin(async { Ok(()) })
+    }
+
+    fn error_report(&self, code: String) -> StoreFuture<'_, Option<ErrorReport>> {
+        let report = self.reports.get(&code).map(|report| report.clone());
+
+        Box::pin(async move { Ok(report) })
+    }
+}
@@ -0,0 +1,97 @@
+use std::{
+    future::Future,
+    pin::Pin
+};
+
+use dashmap::DashMap;
+
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+use crate::system::{
+    model::{
+        error_report::ErrorReport,
+        infraction_update_type::InfractionUpdateType,
+        infractions::Infraction,
+        scheduled_action::ScheduledAction
+    },
+    SystemResult
+};
+
+#[cfg(test)]
+mod in_memory;
+mod postgres;
+
+#[cfg(test)]
+crate use in_memory::{
+    InMemoryConfigStore,
+    InMemoryErrorReportStore,
+    InMemoryInfractionStore,
+    InMemoryScheduledActionStore,
+    InMemoryWhitelistStore
+};
+crate use postgres::{
+    PostgresConfigStore,
+    PostgresErrorReportStore,
+    PostgresInfractionStore,
+    PostgresScheduledActionStore,
+    PostgresWhitelistStore
+};
+
+crate type StoreFuture<'a, T> = Pin<Box<dyn Future<Output = SystemResult<T>> + Send + 'a>>;
+
+/// Where the infractions of the members of each guild are kept.
+crate trait InfractionStore: Send + Sync {
+    /// Stores an infraction under the next case number of the guild, returning the case number.
+    fn add_infraction(&self, guild_id: GuildId, user_id: UserId, infraction: Infraction) -> StoreFuture<'_, u64>;
+
+    fn user_infractions(&self, guild_id: GuildId, user_id: UserId) -> StoreFuture<'_, Vec<Infraction>>;
+
+    fn guild_infractions(&self, guild_id: GuildId) -> StoreFuture<'_, DashMap<UserId, Vec<Infraction>>>;
+
+    /// Updates an infraction, returning it as it was before the update.
+    fn update_infraction(&self, guild_id: GuildId, user_id: UserId, infraction_id: String,
+                         update_type: InfractionUpdateType) -> StoreFuture<'_, Infraction>;
+
+    fn remove_infraction(&self, guild_id: GuildId, user_id: UserId, infraction_id: String) -> StoreFuture<'_, ()>;
+
+    fn clear_infractions(&self, guild_id: GuildId, user_id: UserId) -> StoreFuture<'_, ()>;
+}
+
+/// Where the actions that are due at a later time are kept until they are done or cancelled.
+crate trait ScheduledActionStore: Send + Sync {
+    /// Stores an action, returning the ID it is stored under.
+    fn add_scheduled_action(&self, action: ScheduledAction) -> StoreFuture<'_, u64>;
+
+    fn scheduled_actions(&self) -> StoreFuture<'_, Vec<ScheduledAction>>;
+
+    fn remove_scheduled_action(&self, id: u64) -> StoreFuture<'_, ()>;
+}
+
+/// Where the XML configuration of each guild is kept.
+crate trait ConfigStore: Send + Sync {
+    fn guild_configuration(&self, guild_id: GuildId) -> StoreFuture<'_, String>;
+
+    /// Replaces the configuration of a guild; the cached configuration of the guild is invalidated by the
+    /// `ConfigChanged` this emits.
+    fn set_guild_configuration(&self, guild_id: GuildId, config: String) -> StoreFuture<'_, ()>;
+}
+
+/// Where the guilds that may use the bot are kept.
+crate trait WhitelistStore: Send + Sync {
+    fn whitelisted_guilds(&self) -> StoreFuture<'_, Vec<GuildId>>;
+
+    /// Sets up a newly whitelisted guild with the default configuration.
+    fn initialize_guild(&self, guild_id: GuildId) -> StoreFuture<'_, ()>;
+}
+
+/// Where the reports of the errors raised by commands are kept, under their codes.
+crate trait ErrorReportStore: Send + Sync {
+    fn add_error_report(&self, report: ErrorReport) -> StoreFuture<'_, ()>;
+
+    fn error_report(&self, code: String) -> StoreFuture<'_, Option<ErrorReport>>;
+}
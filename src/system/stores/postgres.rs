@@ -0,0 +1,206 @@
+use std::{
+    sync::Arc
+};
+
+use dashmap::DashMap;
+
+use sqlx::postgres::PgPool;
+
+use twilight_http::{
+    Client
+};
+
+use twilight_model::{
+    id::{
+        GuildId,
+        UserId
+    }
+};
+
+use crate::command_system::events::emitter::CommandEventEmitter;
+
+use crate::system::{
+    model::{
+        error_report::ErrorReport,
+        infraction_update_type::InfractionUpdateType,
+        infractions::Infraction,
+        scheduled_action::ScheduledAction
+    },
+    twilight_http_client_extensions::database_manipulation::{
+        AddErrorReport,
+        AddScheduledAction,
+        AddUserInfraction,
+        ClearUserInfractions,
+        GetErrorReport,
+        GetGuildConfiguration,
+        GetGuildInfractions,
+        GetLocalUserInfractions,
+        GetScheduledActions,
+        GetWhitelistedGuilds,
+        InitializeWhitelistedGuild,
+        RemoveScheduledAction,
+        RemoveUserInfraction,
+        SetGuildConfiguration,
+        UpdateUserInfraction
+    }
+};
+
+use super::{
+    ConfigStore,
+    ErrorReportStore,
+    InfractionStore,
+    ScheduledActionStore,
+    StoreFuture,
+    WhitelistStore
+};
+
+/// The infractions in the Postgres database, which emits the events of the changes made to them.
+crate struct PostgresInfractionStore {
+    connection: PgPool,
+    emitter: CommandEventEmitter
+}
+
+impl PostgresInfractionStore {
+    crate fn new(connection: PgPool, emitter: CommandEventEmitter) -> Self {
+        Self {
+            connection,
+            emitter
+        }
+    }
+}
+
+impl InfractionStore for PostgresInfractionStore {
+    fn add_infraction(&self, guild_id: GuildId, user_id: UserId, infraction: Infraction) -> StoreFuture<'_, u64> {
+        Box::pin(AddUserInfraction::new(self.connection.clone(), guild_id, user_id, infraction, self.emitter.clone()))
+    }
+
+    fn user_infractions(&self, guild_id: GuildId, user_id: UserId) -> StoreFuture<'_, Vec<Infraction>> {
+        Box::pin(GetLocalUserInfractions::new(self.connection.clone(), guild_id, user_id))
+    }
+
+    fn guild_infractions(&self, guild_id: GuildId) -> StoreFuture<'_, DashMap<UserId, Vec<Infraction>>> {
+        Box::pin(GetGuildInfractions::new(self.connection.clone(), guild_id))
+    }
+
+    fn update_infraction(&self, guild_id: GuildId, user_id: UserId, infraction_id: String,
+                         update_type: InfractionUpdateType) -> StoreFuture<'_, Infraction> {
+        Box::pin(UpdateUserInfraction::new(self.connection.clone(), infraction_id, guild_id, user_id, update_type,
+                                           self.emitter.clone()))
+    }
+
+    fn remove_infraction(&self, guild_id: GuildId, user_id: UserId, infraction_id: String) -> StoreFuture<'_, ()> {
+        Box::pin(RemoveUserInfraction::new(self.connection.clone(), infraction_id, guild_id, user_id,
+                                           self.emitter.clone()))
+    }
+
+    fn clear_infractions(&self, guild_id: GuildId, user_id: UserId) -> StoreFuture<'_, ()> {
+        Box::pin(ClearUserInfractions::new(self.connection.clone(), guild_id, user_id, self.emitter.clone()))
+    }
+}
+
+/// The scheduled actions in the Postgres database.
+crate struct PostgresScheduledActionStore {
+    connection: PgPool
+}
+
+impl PostgresScheduledActionStore {
+    crate fn new(connection: PgPool) -> Self {
+        Self {
+            connection
+        }
+    }
+}
+
+impl ScheduledActionStore for PostgresScheduledActionStore {
+    fn add_scheduled_action(&self, action: ScheduledAction) -> StoreFuture<'_, u64> {
+        Box::pin(AddScheduledAction::new(self.connection.clone(), action))
+    }
+
+    fn scheduled_actions(&self) -> StoreFuture<'_, Vec<ScheduledAction>> {
+        Box::pin(GetScheduledActions::new(self.connection.clone()))
+    }
+
+    fn remove_scheduled_action(&self, id: u64) -> StoreFuture<'_, ()> {
+        Box::pin(RemoveScheduledAction::new(self.connection.clone(), id))
+    }
+}
+
+/// The guild configurations in the Postgres database, which emits the events of the changes made to them.
+crate struct PostgresConfigStore {
+    connection: PgPool,
+    emitter: CommandEventEmitter
+}
+
+impl PostgresConfigStore {
+    crate fn new(connection: PgPool, emitter: CommandEventEmitter) -> Self {
+        Self {
+            connection,
+            emitter
+        }
+    }
+}
+
+impl ConfigStore for PostgresConfigStore {
+    fn guild_configuration(&self, guild_id: GuildId) -> StoreFuture<'_, String> {
+        Box::pin(GetGuildConfiguration::new(self.connection.clone(), guild_id))
+    }
+
+    fn set_guild_configuration(&self, guild_id: GuildId, config: String) -> StoreFuture<'_, ()> {
+        Box::pin(SetGuildConfiguration::new(self.connection.clone(), guild_id, config, self.emitter.clone()))
+    }
+}
+
+/// The whitelisted guilds in the Postgres database; setting up a guild looks up its owner on Discord, writes its
+/// default configuration, and emits the events of the guild being whitelisted.
+crate struct PostgresWhitelistStore {
+    connection: PgPool,
+    http_client: Client,
+    configs: Arc<dyn ConfigStore>,
+    emitter: CommandEventEmitter
+}
+
+impl PostgresWhitelistStore {
+    crate fn new(connection: PgPool, http_client: Client, configs: Arc<dyn ConfigStore>, emitter: CommandEventEmitter)
+        -> Self {
+        Self {
+            connection,
+            http_client,
+            configs,
+            emitter
+        }
+    }
+}
+
+impl WhitelistStore for PostgresWhitelistStore {
+    fn whitelisted_guilds(&self) -> StoreFuture<'_, Vec<GuildId>> {
+        Box::pin(GetWhitelistedGuilds::new(self.connection.clone()))
+    }
+
+    fn initialize_guild(&self, guild_id: GuildId) -> StoreFuture<'_, ()> {
+        Box::pin(InitializeWhitelistedGuild::new(self.http_client.clone(), guild_id, self.configs.clone(),
+                                                 self.emitter.clone()))
+    }
+}
+
+/// The error reports in the Postgres database.
+crate struct PostgresErrorReportStore {
+    connection: PgPool
+}
+
+impl PostgresErrorReportStore {
+    crate fn new(connection: PgPool) -> Self {
+        Self {
+            connection
+        }
+    }
+}
+
+impl ErrorReportStore for PostgresErrorReportStore {
+    fn add_error_report(&self, report: ErrorReport) -> StoreFuture<'_, ()> {
+        Box::pin(AddErrorReport::new(self.connection.clone(), report))
+    }
+
+    fn error_report(&self, code: String) -> StoreFuture<'_, Option<ErrorReport>> {
+        Box::pin(GetErrorReport::new(self.connection.clone(), code))
+    }
+}
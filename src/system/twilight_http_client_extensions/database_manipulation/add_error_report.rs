@@ -0,0 +1,89 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll
+    }
+};
+
+use sqlx::{
+    postgres::PgPool
+};
+
+use crate::logging::logger::Logger;
+use crate::system::{
+    model::error_report::ErrorReport,
+    twilight_http_client_extensions::{
+        error::ClientExtensionResult,
+        Pending
+    }
+};
+
+crate struct AddErrorReport {
+    future: Option<Pending<()>>,
+
+    connection: PgPool,
+    report: ErrorReport
+}
+
+impl AddErrorReport {
+    crate fn new(connection: PgPool, report: ErrorReport) -> Self {
+        Self {
+            future: None,
+
+            connection,
+            report
+        }
+    }
+
+    fn start(&mut self) -> ClientExtensionResult<()> {
+        self.future.replace(Box::pin(request(self.connection.clone(), self.report.clone())));
+
+        Ok(())
+    }
+}
+
+impl Future for AddErrorReport {
+    type Output = ClientExtensionResult<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future.as_mut() {
+                return future.as_mut().poll(cx);
+            }
+
+            if let Err(error) = self.start() {
+                return Poll::Ready(Err(error));
+            }
+        }
+    }
+}
+
+unsafe impl Send for AddErrorReport {}
+
+async fn request(connection: PgPool, report: ErrorReport) -> ClientExtensionResult<()> {
+    Logger::log_debug(format!("Making query to database. [Storing error report: {}]", report.code));
+
+    // The same error raised by the same message has the same code, so it is only stored once.
+    if let Err(error) = sqlx::query(
+        // language=SQL
+        "INSERT INTO error_reports (code, reported_at, command, arguments, guild_id, channel_id, user_id, error_chain) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) ON CONFLICT (code) DO NOTHING; --"
+    )
+        .bind(report.code.clone())
+        .bind(report.reported_at)
+        .bind(report.command.clone())
+        .bind(report.arguments.clone())
+        .bind(report.guild_id.map(|guild_id| guild_id.0 as i64))
+        .bind(report.channel_id.0 as i64)
+        .bind(report.user_id.0 as i64)
+        .bind(report.error_chain.clone())
+        .execute(&connection)
+        .await {
+        Logger::log_error(format!("Could not store error report. Error: {}", error));
+
+        return Err(box error);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll
+    }
+};
+
+use sqlx::{
+    postgres::PgPool,
+    Row
+};
+
+use crate::logging::logger::Logger;
+use crate::system::{
+    model::scheduled_action::ScheduledAction,
+    twilight_http_client_extensions::{
+        error::ClientExtensionResult,
+        Pending
+    }
+};
+
+crate struct AddScheduledAction {
+    future: Option<Pending<u64>>,
+
+    connection: PgPool,
+    action: ScheduledAction
+}
+
+impl AddScheduledAction {
+    crate fn new(connection: PgPool, action: ScheduledAction) -> Self {
+        Self {
+            future: None,
+
+            connection,
+            action
+        }
+    }
+
+    fn start(&mut self) -> ClientExtensionResult<()> {
+        self.future.replace(Box::pin(request(self.connection.clone(), self.action.clone())));
+
+        Ok(())
+    }
+}
+
+impl Future for AddScheduledAction {
+    type Output = ClientExtensionResult<u64>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future.as_mut() {
+                return future.as_mut().poll(cx);
+            }
+
+            if let Err(error) = self.start() {
+                return Poll::Ready(Err(error));
+            }
+        }
+    }
+}
+
+unsafe impl Send for AddScheduledAction {}
+
+async fn request(connection: PgPool, action: ScheduledAction) -> ClientExtensionResult<u64> {
+    Logger::log_debug(
+        format!("Making query to database. [Scheduling action `{}` for user: {}]", action.kind.name(), action.user_id));
+
+    let row = match sqlx::query(
+        // language=SQL
+        "INSERT INTO scheduled_actions (guild_id, user_id, action, role_id, role_to_restore, due_at) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id; --"
+    )
+        .bind(action.guild_id.0 as i64)
+        .bind(action.user_id.0 as i64)
+        .bind(action.kind.name())
+        .bind(action.kind.role_id().map(|role_id| role_id.0 as i64))
+        .bind(action.kind.role_to_restore().map(|role_id| role_id.0 as i64))
+        .bind(action.due_at)
+        .fetch_one(&connection)
+        .await {
+        Ok(row) => row,
+        Err(error) => {
+            Logger::log_error(format!("Could not store scheduled action. Error: {}", error));
+
+            return Err(box error);
+        }
+    };
+
+    let id: i64 = row.get("id");
+
+    Ok(id as u64)
+}
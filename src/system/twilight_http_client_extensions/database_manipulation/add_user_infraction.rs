@@ -1,6 +1,5 @@
 use std::{
     future::Future,
-    env::*,
     pin::Pin,
     task::{
         Context,
@@ -9,13 +8,7 @@ use std::{
 };
 
 use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
-    postgres::{
-        PgPool,
-        PgRow
-    },
+    postgres::PgPool,
     Row
 };
 
@@ -26,53 +19,59 @@ use twilight_model::{
     },
 };
 
-use crate::command_system::CommandError;
+use crate::command_system::{
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    }
+};
 use crate::logging::logger::Logger;
 use crate::system::{
-    model::infractions::InfractionType,
+    model::{
+        infractions::Infraction,
+        payload::InfractionCreated
+    },
     twilight_http_client_extensions::{
         error::ClientExtensionResult,
         Pending
-    },
-    twilight_id_extensions::IntoInnerU64
+    }
 };
 
+/// Adds an infraction to a user, giving it the next case number of the guild, which the future resolves to.
 crate struct AddUserInfraction {
-    future: Option<Pending<()>>,
+    future: Option<Pending<u64>>,
 
-    infraction_id: String,
+    connection: PgPool,
     guild_id: GuildId,
     user_id: UserId,
-    reason: String,
-    infraction_type: InfractionType,
+    infraction: Infraction,
+    emitter: CommandEventEmitter
 }
 
 impl AddUserInfraction {
-    crate fn new(infraction_id: String, guild_id: GuildId, user_id: UserId, reason: String,
-               infraction_type: InfractionType) -> Self {
+    crate fn new(connection: PgPool, guild_id: GuildId, user_id: UserId, infraction: Infraction,
+               emitter: CommandEventEmitter) -> Self {
         AddUserInfraction {
             future: None,
 
-            infraction_id,
+            connection,
             guild_id,
             user_id,
-            reason,
-            infraction_type,
+            infraction,
+            emitter
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        Logger::log_debug("Attempting to create connection to HarTexBetaGuildInfractions database.".to_string());
-
-        self.future.replace(Box::pin(request(self.infraction_id.clone(), self.guild_id, self.user_id,
-                                             self.reason.clone(), self.infraction_type)));
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id, self.user_id, self.infraction.clone(),
+                                             self.emitter.clone())));
 
         Ok(())
     }
 }
 
 impl Future for AddUserInfraction {
-    type Output = ClientExtensionResult<()>;
+    type Output = ClientExtensionResult<u64>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         loop {
@@ -89,112 +88,61 @@ impl Future for AddUserInfraction {
 
 unsafe impl Send for AddUserInfraction {}
 
-async fn request(infraction_id: String, guild_id: GuildId, user_id: UserId, reason: String,
-                 infraction_type: InfractionType) -> ClientExtensionResult<()> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_INFRACTIONS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
-    Logger::log_debug(
-        format!(
-            "Making query to database. [Checking if guild schema exists in the database: {}]", guild_id));
-
-    let schema_query_result: SqlxResult<PgRow> = sqlx::query(
-        // language=SQL
-        "SELECT EXISTS (SELECT * FROM INFORMATION_SCHEMA.SCHEMATA WHERE SCHEMA_NAME = $1); --"
-    )
-        .bind::<String>(format!("inf_{}", guild_id.into_inner_u64()))
-        .fetch_one(&connection).await;
-
-    let schema_exists = match schema_query_result {
-        Ok(row) => {
-            Logger::log_verbose("Successfully made query.");
-
-            let exists: bool = row.get("exists");
+async fn request(connection: PgPool, guild_id: GuildId, user_id: UserId, mut infraction: Infraction,
+                 emitter: CommandEventEmitter) -> ClientExtensionResult<u64> {
+    // The row of the guild is locked until the transaction ends, so concurrent infractions get different case numbers,
+    // and a case number is only taken when its infraction is added.
+    let mut transaction = connection.begin().await?;
 
-            exists
-        },
-        Err(error) => {
-            Logger::log_error(
-                format!("Failed check if schema exists. Error: {}", error.as_database_error().unwrap()));
-
-            return Err(box error);
-        }
-    };
-
-    if !schema_exists {
-        if let Err(error) = sqlx::query(
-            // language=SQL
-            "CREATE SCHEMA $1; --"
-        )
-            .bind(format!("inf_{}", guild_id.0))
-            .fetch_one(&connection)
-            .await {
-            Logger::log_error(format!("Could not create schema. Error: {}", error.as_database_error().unwrap()));
-
-            return Err(box error);
-        }
-    }
-
-    let table_query_result: SqlxResult<PgRow> = sqlx::query(
+    let case_number = match sqlx::query(
         // language=SQL
-        "SELECT EXISTS (SELECT * FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_NAME = CONCAT('user_', $1) AND TABLE_SCHEMA = CONCAT('inf_', $2))"
+        "INSERT INTO infraction_case_numbers (guild_id, last_case_number) VALUES ($1, 1) ON CONFLICT (guild_id) \
+         DO UPDATE SET last_case_number = infraction_case_numbers.last_case_number + 1 RETURNING last_case_number; --"
     )
-        .bind::<String>(user_id.0.to_string())
-        .bind(guild_id.0.to_string())
-        .fetch_one(&connection)
-        .await;
-    let table_exists = match table_query_result {
-        Ok(row) => {
-            Logger::log_verbose("Successfully made query.");
-
-            let exists: bool = row.get("exists");
-
-            exists
-        },
+        .bind(guild_id.0 as i64)
+        .fetch_one(&mut transaction)
+        .await {
+        Ok(row) => row.get::<i64, &str>("last_case_number") as u64,
         Err(error) => {
-            Logger::log_error(format!("Failed to check if table exists. Error: {}", error.as_database_error().unwrap()));
+            Logger::log_error(format!("Could not allocate case number. Error: {}", error));
 
-            return Err(box error);
+            return Err(box error)
         }
     };
 
-    if !table_exists {
-        if let Err(error) = sqlx::query(
-            // language=SQL
-            "CREATE TABLE $1 (infraction_id TEXT, reason TEXT, infraction_type TEXT)"
-        )
-            .bind(format!("inf_{}.user_{}", guild_id.0, user_id.0))
-            .fetch_all(&connection)
-            .await {
-            Logger::log_error(format!("Could not create table. Error: {}", error.as_database_error().unwrap()));
-
-            return Err(box error)
-        }
-    }
+    infraction.infraction_id = case_number.to_string();
 
     if let Err(error) = sqlx::query(
-        &format!(
-            // language=SQL
-            "INSERT INTO {} (infraction_id, reason, infraction_type) VALUES ($1, $2, $3)",
-            format!("inf_{}.user_{}", guild_id.0, user_id.0))
+        // language=SQL
+        "INSERT INTO infractions (guild_id, user_id, infraction_id, reason, infraction_type, moderator_id, created_at, \
+         expires_at, active) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9); --"
     )
-        .bind(infraction_id.clone())
-        .bind(reason.clone())
-        .bind(infraction_type.to_string())
-        .fetch_all(&connection)
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
+        .bind(infraction.infraction_id.clone())
+        .bind(infraction.reason.clone())
+        .bind(infraction.infraction_type.to_string())
+        .bind(infraction.moderator_id.map(|moderator_id| moderator_id.0 as i64))
+        .bind(infraction.created_at)
+        .bind(infraction.expires_at)
+        .bind(infraction.active)
+        .execute(&mut transaction)
         .await {
-        Logger::log_error(format!("Could not delete infraction. Error: {}", error));
+        Logger::log_error(format!("Could not add infraction. Error: {}", error));
 
         return Err(box error)
     }
 
-    Ok(())
+    transaction.commit().await?;
+
+    emitter.event(SystemEvent::InfractionCreated(box InfractionCreated {
+        guild_id,
+        user_id,
+        moderator_id: infraction.moderator_id,
+        infraction_id: infraction.infraction_id,
+        reason: infraction.reason,
+        infraction_type: infraction.infraction_type
+    })).await;
+
+    Ok(case_number)
 }
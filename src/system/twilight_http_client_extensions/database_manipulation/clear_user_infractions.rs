@@ -1,6 +1,5 @@
 use std::{
     future::Future,
-    env::*,
     pin::Pin,
     task::{
         Context,
@@ -9,14 +8,7 @@ use std::{
 };
 
 use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
-    postgres::{
-        PgPool,
-        PgRow
-    },
-    Row
+    postgres::PgPool
 };
 
 use twilight_model::{
@@ -26,37 +18,43 @@ use twilight_model::{
     },
 };
 
-use crate::command_system::CommandError;
-use crate::logging::logger::Logger;
+use crate::command_system::{
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    }
+};
 use crate::system::{
+    model::payload::InfractionsCleared,
     twilight_http_client_extensions::{
         error::ClientExtensionResult,
         Pending
-    },
-    twilight_id_extensions::IntoInnerU64
+    }
 };
 
 crate struct ClearUserInfractions {
     future: Option<Pending<()>>,
 
+    connection: PgPool,
     guild_id: GuildId,
-    user_id: UserId
+    user_id: UserId,
+    emitter: CommandEventEmitter
 }
 
 impl ClearUserInfractions {
-    crate fn new(guild_id: GuildId, user_id: UserId) -> Self {
+    crate fn new(connection: PgPool, guild_id: GuildId, user_id: UserId, emitter: CommandEventEmitter) -> Self {
         Self {
             future: None,
 
+            connection,
             guild_id,
-            user_id
+            user_id,
+            emitter
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        Logger::log_debug("Attempting to create connection to HarTexBetaGuildInfractions database.".to_string());
-
-        self.future.replace(Box::pin(request(self.guild_id, self.user_id)));
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id, self.user_id, self.emitter.clone())));
 
         Ok(())
     }
@@ -80,86 +78,23 @@ impl Future for ClearUserInfractions {
 
 unsafe impl Send for ClearUserInfractions {}
 
-async fn request(guild_id: GuildId, user_id: UserId) -> ClientExtensionResult<()> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_INFRACTIONS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
-    Logger::log_debug(
-        format!(
-            "Making query to database. [Checking if guild schema exists in the database: {}]", guild_id));
-
-    let schema_query_result: SqlxResult<PgRow> = sqlx::query(
-        // language=SQL
-        "SELECT EXISTS (SELECT * FROM INFORMATION_SCHEMA.SCHEMATA WHERE SCHEMA_NAME = $1); --"
-    )
-        .bind::<String>(format!("inf_{}", guild_id.into_inner_u64()))
-        .fetch_one(&connection).await;
-
-    let schema_exists = match schema_query_result {
-        Ok(row) => {
-            Logger::log_verbose("Successfully made query.");
-
-            let exists: bool = row.get("exists");
-
-            exists
-        },
-        Err(error) => {
-            Logger::log_error(
-                format!("Failed check if schema exists. Error: {}", error.as_database_error().unwrap()));
-
-            return Err(box error);
-        }
-    };
-
-    if !schema_exists {
-        return Ok(());
-    }
-
-    let table_query_result: SqlxResult<PgRow> = sqlx::query(
-        // language=SQL
-        "SELECT EXISTS (SELECT * FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_NAME = CONCAT('user_', $1) AND TABLE_SCHEMA = CONCAT('inf_', $2))"
-    )
-        .bind::<String>(user_id.0.to_string())
-        .bind(guild_id.0.to_string())
-        .fetch_one(&connection)
-        .await;
-    let table_exists = match table_query_result {
-        Ok(row) => {
-            Logger::log_verbose("Successfully made query.");
-
-            let exists: bool = row.get("exists");
-
-            exists
-        },
-        Err(error) => {
-            Logger::log_error(format!("Failed to check if table exists. Error: {}", error.as_database_error().unwrap()));
-
-            return Err(box error);
-        }
-    };
-
-    if !table_exists {
-        return Ok(());
-    }
-
+async fn request(connection: PgPool, guild_id: GuildId, user_id: UserId, emitter: CommandEventEmitter)
+    -> ClientExtensionResult<()> {
     if let Err(error) = sqlx::query(
-        &format!(
-            // language=SQL
-            "DELETE FROM {}",
-            format!("inf_{}.user_{}", guild_id.0, user_id.0))
+        // language=SQL
+        "DELETE FROM infractions WHERE guild_id = $1 AND user_id = $2; --"
     )
-        .fetch_all(&connection)
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
+        .execute(&connection)
         .await {
         return Err(box error)
     }
 
-    todo!()
+    emitter.event(SystemEvent::InfractionsCleared(box InfractionsCleared {
+        guild_id,
+        user_id
+    })).await;
+
+    Ok(())
 }
@@ -0,0 +1,108 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll
+    }
+};
+
+use chrono::{
+    DateTime,
+    Utc
+};
+
+use sqlx::{
+    postgres::PgPool,
+    Row
+};
+
+use twilight_model::{
+    id::{
+        ChannelId,
+        GuildId,
+        UserId
+    }
+};
+
+use crate::logging::logger::Logger;
+use crate::system::{
+    model::error_report::ErrorReport,
+    twilight_http_client_extensions::{
+        error::ClientExtensionResult,
+        Pending
+    }
+};
+
+crate struct GetErrorReport {
+    future: Option<Pending<Option<ErrorReport>>>,
+
+    connection: PgPool,
+    code: String
+}
+
+impl GetErrorReport {
+    crate fn new(connection: PgPool, code: String) -> Self {
+        Self {
+            future: None,
+
+            connection,
+            code
+        }
+    }
+
+    fn start(&mut self) -> ClientExtensionResult<()> {
+        self.future.replace(Box::pin(request(self.connection.clone(), self.code.clone())));
+
+        Ok(())
+    }
+}
+
+impl Future for GetErrorReport {
+    type Output = ClientExtensionResult<Option<ErrorReport>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future.as_mut() {
+                return future.as_mut().poll(cx);
+            }
+
+            if let Err(error) = self.start() {
+                return Poll::Ready(Err(error));
+            }
+        }
+    }
+}
+
+unsafe impl Send for GetErrorReport {}
+
+async fn request(connection: PgPool, code: String) -> ClientExtensionResult<Option<ErrorReport>> {
+    Logger::log_debug(format!("Making query to database. [Getting error report: {}]", code));
+
+    let row = match sqlx::query(
+        // language=SQL
+        "SELECT * FROM error_reports WHERE code = $1; --"
+    )
+        .bind(code)
+        .fetch_optional(&connection)
+        .await {
+        Ok(Some(row)) => row,
+        Ok(None) => return Ok(None),
+        Err(error) => {
+            Logger::log_error(format!("Could not get error report. Error: {}", error));
+
+            return Err(box error);
+        }
+    };
+
+    Ok(Some(ErrorReport {
+        code: row.get("code"),
+        reported_at: row.get::<DateTime<Utc>, &str>("reported_at"),
+        command: row.get("command"),
+        arguments: row.get("arguments"),
+        guild_id: row.get::<Option<i64>, &str>("guild_id").map(|guild_id| GuildId(guild_id as u64)),
+        channel_id: ChannelId(row.get::<i64, &str>("channel_id") as u64),
+        user_id: UserId(row.get::<i64, &str>("user_id") as u64),
+        error_chain: row.get("error_chain")
+    }))
+}
@@ -2,7 +2,6 @@ extern crate base64;
 
 use std::{
     future::Future,
-    env::*,
     pin::Pin,
     task::{
         Context,
@@ -42,23 +41,22 @@ use crate::system::{
 crate struct GetGuildConfiguration {
     future: Option<Pending<String>>,
 
+    connection: PgPool,
     guild_id: GuildId,
 }
 
 impl GetGuildConfiguration {
-    crate fn new(guild_id: GuildId) -> GetGuildConfiguration {
+    crate fn new(connection: PgPool, guild_id: GuildId) -> GetGuildConfiguration {
         GetGuildConfiguration {
             future: None,
 
+            connection,
             guild_id,
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        Logger::log_debug("Attempting to create connection to HarTexBetaGuildConfiguration database."
-            .to_string());
-
-        self.future.replace(Box::pin(request(self.guild_id)));
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id)));
 
         Ok(())
     }
@@ -82,18 +80,7 @@ impl Future for GetGuildConfiguration {
 
 unsafe impl Send for GetGuildConfiguration {}
 
-async fn request(guild_id: GuildId) -> ClientExtensionResult<String> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_CONFIGURATION") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
+async fn request(connection: PgPool, guild_id: GuildId) -> ClientExtensionResult<String> {
     Logger::log_debug(
         format!("Making query to database. [Getting guild configuration for guild: {}]", guild_id
         )
@@ -116,7 +103,7 @@ async fn request(guild_id: GuildId) -> ClientExtensionResult<String> {
                 )
             }
             else {
-                Err(box CommandError("Configuration not found".to_string()))
+                Err(box CommandError::User("The configuration of this server was not found.".to_string()))
             }
         }
         Err(error) => {
@@ -1,5 +1,4 @@
 use std::{
-    env::*,
     future::Future,
     pin::Pin,
     task::{
@@ -11,18 +10,10 @@ use std::{
 use dashmap::DashMap;
 
 use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
-    postgres::{
-        PgPool,
-        PgRow
-    },
+    postgres::PgPool,
     Row
 };
 
-use twilight_http::Client;
-
 use twilight_model::{
     id::{
         GuildId,
@@ -30,40 +21,35 @@ use twilight_model::{
     }
 };
 
-use crate::command_system::CommandError;
-use crate::logging::logger::Logger;
 use crate::system::{
-    model::infractions::{
-        Infraction,
-        InfractionType
-    },
+    model::infractions::Infraction,
     twilight_http_client_extensions::{
         error::ClientExtensionResult,
-        GetLocalUserInfractions,
         Pending
-    },
-    twilight_id_extensions::IntoInnerU64
+    }
 };
 
+use super::get_local_user_infractions::infraction_from_row;
+
 crate struct GetGuildInfractions {
     future: Option<Pending<DashMap<UserId, Vec<Infraction>>>>,
 
-    guild_id: GuildId,
-    http_client: Client
+    connection: PgPool,
+    guild_id: GuildId
 }
 
 impl GetGuildInfractions {
-    crate fn new(guild_id: GuildId, http_client: Client) -> Self {
+    crate fn new(connection: PgPool, guild_id: GuildId) -> Self {
         Self {
             future: None,
 
-            guild_id,
-            http_client
+            connection,
+            guild_id
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        self.future.replace(Box::pin(request(self.guild_id, self.http_client.clone())));
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id)));
 
         Ok(())
     }
@@ -87,44 +73,23 @@ impl Future for GetGuildInfractions {
 
 unsafe impl Send for GetGuildInfractions {}
 
-async fn request(guild_id: GuildId, http_client: Client) -> ClientExtensionResult<DashMap<UserId, Vec<Infraction>>> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_INFRACTIONS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
-    let tables_query_result = sqlx::query(
-        &format!(
-            // language=SQL
-            "SELECT TABLE_NAME FROM INFORMATION_SCHEMA.TABLES WHERE TABLE_SCHEMA = $1"
-        )
+async fn request(connection: PgPool, guild_id: GuildId)
+    -> ClientExtensionResult<DashMap<UserId, Vec<Infraction>>> {
+    let rows = sqlx::query(
+        // language=SQL
+        "SELECT * FROM infractions WHERE guild_id = $1 ORDER BY id; --"
     )
-        .bind::<String>(format!("inf_{}", guild_id.into_inner_u64()))
+        .bind(guild_id.0 as i64)
         .fetch_all(&connection)
-        .await;
+        .await?;
 
-    match tables_query_result {
-        Ok(rows) => {
-            let infraction_map = DashMap::<UserId, Vec<Infraction>>::new();
+    let infraction_map = DashMap::<UserId, Vec<Infraction>>::new();
 
-            for row in rows {
-                let name: String = row.get("table_name");
-                let user_id = UserId::from(name.get(5..).unwrap().parse::<u64>().unwrap());
-                let infractions = http_client.clone().get_local_user_infractions(guild_id, user_id).await?;
+    for row in rows.iter() {
+        let user_id = UserId(row.get::<i64, &str>("user_id") as u64);
 
-                infraction_map.insert(user_id, infractions);
-            }
-
-            Ok(infraction_map)
-        },
-        Err(error) => {
-            Err(box error)
-        }
+        infraction_map.entry(user_id).or_default().push(infraction_from_row(row)?);
     }
+
+    Ok(infraction_map)
 }
@@ -1,5 +1,4 @@
 use std::{
-    env::*,
     future::Future,
     pin::Pin,
     task::{
@@ -8,10 +7,12 @@ use std::{
     }
 };
 
+use chrono::{
+    DateTime,
+    Utc
+};
+
 use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
     postgres::{
         PgPool,
         PgRow
@@ -27,38 +28,39 @@ use twilight_model::{
 };
 
 use crate::command_system::CommandError;
-use crate::logging::logger::Logger;
 use crate::system::{
     model::infractions::{
         Infraction,
+        InfractionRevocation,
         InfractionType
     },
     twilight_http_client_extensions::{
         error::ClientExtensionResult,
         Pending
-    },
-    twilight_id_extensions::IntoInnerU64
+    }
 };
 
 crate struct GetLocalUserInfractions {
     future: Option<Pending<Vec<Infraction>>>,
 
+    connection: PgPool,
     guild_id: GuildId,
     user_id: UserId,
 }
 
 impl GetLocalUserInfractions {
-    crate fn new(guild_id: GuildId, user_id: UserId) -> Self {
+    crate fn new(connection: PgPool, guild_id: GuildId, user_id: UserId) -> Self {
         Self {
             future: None,
 
+            connection,
             guild_id,
             user_id,
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        self.future.replace(Box::pin(request(self.guild_id, self.user_id)));
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id, self.user_id)));
 
         Ok(())
     }
@@ -82,59 +84,47 @@ impl Future for GetLocalUserInfractions {
 
 unsafe impl Send for GetLocalUserInfractions {}
 
-async fn request(guild_id: GuildId, user_id: UserId) -> ClientExtensionResult<Vec<Infraction>> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_INFRACTIONS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
-    let query_result = sqlx::query(
-        &format!(
-            // language=SQL
-            "SELECT * FROM inf_{}.user_{}",
-            guild_id.into_inner_u64(),
-            user_id.into_inner_u64()
-        )
+async fn request(connection: PgPool, guild_id: GuildId, user_id: UserId) -> ClientExtensionResult<Vec<Infraction>> {
+    let rows = sqlx::query(
+        // language=SQL
+        "SELECT * FROM infractions WHERE guild_id = $1 AND user_id = $2 ORDER BY id; --"
     )
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
         .fetch_all(&connection)
-        .await;
+        .await?;
 
-    match query_result {
-        Ok(rows) => {
-            if rows.is_empty() {
-                Ok(Vec::new())
-            }
-            else {
-                let mut infractions = Vec::<Infraction>::new();
-
-                rows.iter().for_each(|row| {
-                    let infraction_id: String = row.get("infraction_id");
-                    let infraction_type = match row.get::<&str, &str>("infraction_type") {
-                        "warning" => InfractionType::Warning,
-                        "mute" => InfractionType::Mute,
-                        "unmute" => InfractionType::Unmute,
-                        "temp-mute" => InfractionType::TemporaryMute,
-                        "kick" => InfractionType::Kick,
-                        "ban" => InfractionType::Ban,
-                        "unban" => InfractionType::Unban,
-                        _ => unreachable!()
-                    };
-                    let reason: String = row.get("reason");
-
-                    infractions.push(Infraction::new(infraction_id, reason, infraction_type));
-                });
-
-                Ok(infractions)
-            }
-        },
-        Err(_) => {
-            Ok(Vec::new())
-        }
-    }
+    rows.iter().map(infraction_from_row).collect()
+}
+
+/// Reads an infraction from its row in the `infractions` table.
+crate fn infraction_from_row(row: &PgRow) -> ClientExtensionResult<Infraction> {
+    let infraction_type: String = row.get("infraction_type");
+    let infraction_type = match InfractionType::from_name(&infraction_type) {
+        Some(infraction_type) => infraction_type,
+        None => return Err(box CommandError::Database(format!("Unknown infraction type `{}`.", infraction_type)))
+    };
+
+    let optional_user_id = |column: &str| row.get::<Option<i64>, &str>(column).map(|user_id| UserId(user_id as u64));
+    let optional_time = |column: &str| row.get::<Option<DateTime<Utc>>, &str>(column);
+
+    let revocation = match (optional_user_id("revoked_by"), optional_time("revoked_at")) {
+        (Some(revoked_by), Some(revoked_at)) => Some(InfractionRevocation {
+            revoked_by,
+            revoked_at,
+            reason: row.get::<Option<String>, &str>("revocation_reason").unwrap_or_default()
+        }),
+        _ => None
+    };
+
+    Ok(Infraction {
+        infraction_id: row.get("infraction_id"),
+        reason: row.get("reason"),
+        infraction_type,
+        moderator_id: optional_user_id("moderator_id"),
+        created_at: optional_time("created_at"),
+        expires_at: optional_time("expires_at"),
+        active: row.get("active"),
+        revocation
+    })
 }
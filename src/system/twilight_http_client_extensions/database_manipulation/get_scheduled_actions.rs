@@ -0,0 +1,126 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll
+    }
+};
+
+use chrono::{
+    DateTime,
+    Utc
+};
+
+use sqlx::{
+    postgres::PgPool,
+    Row
+};
+
+use twilight_model::{
+    id::{
+        GuildId,
+        RoleId,
+        UserId
+    }
+};
+
+use crate::logging::logger::Logger;
+use crate::system::{
+    model::scheduled_action::{
+        ScheduledAction,
+        ScheduledActionKind
+    },
+    twilight_http_client_extensions::{
+        error::ClientExtensionResult,
+        Pending
+    }
+};
+
+crate struct GetScheduledActions {
+    future: Option<Pending<Vec<ScheduledAction>>>,
+
+    connection: PgPool
+}
+
+impl GetScheduledActions {
+    crate fn new(connection: PgPool) -> Self {
+        Self {
+            future: None,
+
+            connection
+        }
+    }
+
+    fn start(&mut self) -> ClientExtensionResult<()> {
+        self.future.replace(Box::pin(request(self.connection.clone())));
+
+        Ok(())
+    }
+}
+
+impl Future for GetScheduledActions {
+    type Output = ClientExtensionResult<Vec<ScheduledAction>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future.as_mut() {
+                return future.as_mut().poll(cx);
+            }
+
+            if let Err(error) = self.start() {
+                return Poll::Ready(Err(error));
+            }
+        }
+    }
+}
+
+unsafe impl Send for GetScheduledActions {}
+
+async fn request(connection: PgPool) -> ClientExtensionResult<Vec<ScheduledAction>> {
+    Logger::log_debug("Making query to database. [Getting scheduled actions]");
+
+    let rows = match sqlx::query(
+        // language=SQL
+        "SELECT * FROM scheduled_actions; --"
+    )
+        .fetch_all(&connection)
+        .await {
+        Ok(rows) => rows,
+        Err(error) => {
+            Logger::log_error(format!("Could not get scheduled actions. Error: {}", error));
+
+            return Err(box error);
+        }
+    };
+
+    let mut actions = Vec::new();
+
+    for row in rows {
+        let id: i64 = row.get("id");
+        let guild_id: i64 = row.get("guild_id");
+        let user_id: i64 = row.get("user_id");
+        let action: String = row.get("action");
+        let role_id = row.get::<Option<i64>, &str>("role_id").map(|role_id| RoleId(role_id as u64));
+        let role_to_restore = row.get::<Option<i64>, &str>("role_to_restore").map(|role_id| RoleId(role_id as u64));
+
+        let kind = match ScheduledActionKind::from_parts(&action, role_id, role_to_restore) {
+            Some(kind) => kind,
+            None => {
+                Logger::log_warning(format!("Skipping scheduled action {} of unknown kind `{}`.", id, action));
+
+                continue;
+            }
+        };
+
+        actions.push(ScheduledAction {
+            id: id as u64,
+            guild_id: GuildId(guild_id as u64),
+            user_id: UserId(user_id as u64),
+            kind,
+            due_at: row.get::<DateTime<Utc>, &str>("due_at")
+        });
+    }
+
+    Ok(actions)
+}
@@ -1,6 +1,5 @@
 use std::{
     convert::TryInto,
-    env::*,
     future::Future,
     pin::Pin,
     task::{
@@ -24,7 +23,6 @@ use twilight_model::{
     id::GuildId
 };
 
-use crate::command_system::CommandError;
 use crate::logging::logger::Logger;
 use super::{
     super::{
@@ -35,30 +33,26 @@ use super::{
 
 crate struct GetWhitelistedGuilds {
     future: Option<Pending<Vec<GuildId>>>,
+
+    connection: PgPool
 }
 
 impl GetWhitelistedGuilds {
-    crate fn new() -> GetWhitelistedGuilds {
+    crate fn new(connection: PgPool) -> GetWhitelistedGuilds {
         GetWhitelistedGuilds {
             future: None,
+
+            connection
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        Logger::log_debug("Attempting to create connection to HarTexBetaGuilds database.".to_string());
-
-        self.future.replace(Box::pin(request()));
+        self.future.replace(Box::pin(request(self.connection.clone())));
 
         Ok(())
     }
 }
 
-impl Default for GetWhitelistedGuilds {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl Future for GetWhitelistedGuilds {
     type Output = ClientExtensionResult<Vec<GuildId>>;
 
@@ -77,17 +71,7 @@ impl Future for GetWhitelistedGuilds {
 
 unsafe impl Send for GetWhitelistedGuilds {}
 
-async fn request() -> ClientExtensionResult<Vec<GuildId>> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILDS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-            &database_credentials
-        ).await?;
+async fn request(connection: PgPool) -> ClientExtensionResult<Vec<GuildId>> {
     let query_reult: SqlxResult<Vec<PgRow>> = sqlx::query("SELECT * FROM guilds; --").fetch_all(&connection).await;
 
     let mut guild_id_vector = Vec::new();
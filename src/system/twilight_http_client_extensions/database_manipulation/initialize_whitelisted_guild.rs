@@ -1,25 +1,14 @@
 use std::{
-    env::*,
     future::Future,
     io::Cursor,
     pin::Pin,
+    sync::Arc,
     task::{
         Context,
         Poll,
     }
 };
 
-use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
-    postgres::{
-        PgPool,
-        PgRow
-    },
-    Row
-};
-
 use tokio_postgres::{
     NoTls,
     connect
@@ -36,10 +25,17 @@ use twilight_model::{
 use quick_xml::Writer;
 
 use crate::command_system::{
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    },
     CommandError
 };
 
-use crate::logging::logger::Logger;
+use crate::system::{
+    model::payload::GuildWhitelisted,
+    stores::ConfigStore
+};
 
 use crate::xml_deserialization::{
     plugin_management::{
@@ -48,6 +44,9 @@ use crate::xml_deserialization::{
                 MuteCommand
             }
         },
+        CommandConfirmations,
+        CommandCooldowns,
+        CommandRestrictions,
         InfractionsPlugin,
         Plugins,
     },
@@ -71,25 +70,27 @@ crate struct InitializeWhitelistedGuild {
 
     guild_id: GuildId,
 
-    http: Client
+    http: Client,
+    configs: Arc<dyn ConfigStore>,
+    emitter: CommandEventEmitter
 }
 
 impl InitializeWhitelistedGuild {
-    crate fn new(http: Client, guild_id: GuildId) -> Self {
+    crate fn new(http: Client, guild_id: GuildId, configs: Arc<dyn ConfigStore>, emitter: CommandEventEmitter) -> Self {
         Self {
             future: None,
 
             guild_id,
 
-            http
+            http,
+            configs,
+            emitter
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        Logger::log_debug(
-            "Attempting to create connection to HarTexBetaGuildConfiguration database.".to_string());
-
-        self.future.replace(Box::pin(request(self.guild_id, self.http.clone())));
+        self.future.replace(Box::pin(request(self.guild_id, self.http.clone(), self.configs.clone(),
+                                             self.emitter.clone())));
 
         Ok(())
     }
@@ -113,18 +114,8 @@ impl Future for InitializeWhitelistedGuild {
 
 unsafe impl Send for InitializeWhitelistedGuild {}
 
-async fn request(guild_id: GuildId, http: Client) -> ClientExtensionResult<()> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_CONFIGURATION") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
+async fn request(guild_id: GuildId, http: Client, configs: Arc<dyn ConfigStore>, emitter: CommandEventEmitter)
+    -> ClientExtensionResult<()> {
     let guild_request = http.guild(guild_id).await?;
 
     if let Some(guild) = guild_request {
@@ -146,18 +137,25 @@ async fn request(guild_id: GuildId, http: Client) -> ClientExtensionResult<()> {
                         muted_role: None,
                         role_to_remove: None
                     }
-                }
+                },
+                command_cooldowns: CommandCooldowns::default(),
+                command_restrictions: CommandRestrictions::default(),
+                command_confirmations: CommandConfirmations::default()
             }
         })?;
 
         let result = writer.into_inner().into_inner();
-        dbg!(String::from_utf8(result)?);
 
-        // Assuming when this is executed, all pre-execution checks are done: the schema and tables for the guild does NOT exist.
+        // The configuration store emits `ConfigChanged` once the configuration is written.
+        configs.set_guild_configuration(guild_id, String::from_utf8(result)?).await?;
+
+        emitter.event(SystemEvent::GuildWhitelisted(box GuildWhitelisted {
+            guild_id
+        })).await;
 
         Ok(())
     }
     else {
-        Err(box CommandError("Guild ID is invalid.".to_string()))
+        Err(box CommandError::User("Guild ID is invalid.".to_string()))
     }
 }
@@ -1,19 +1,31 @@
+mod add_error_report;
+mod add_scheduled_action;
 mod add_user_infraction;
 mod clear_user_infractions;
+mod get_error_report;
 mod get_guild_configuration;
 mod get_guild_infractions;
 mod get_local_user_infractions;
+mod get_scheduled_actions;
 mod get_whitelisted_guilds;
 mod initialize_whitelisted_guild;
+mod remove_scheduled_action;
 mod remove_user_infraction;
+mod set_guild_configuration;
 mod update_user_infraction;
 
+crate use add_error_report::AddErrorReport;
+crate use add_scheduled_action::AddScheduledAction;
 crate use add_user_infraction::AddUserInfraction;
 crate use clear_user_infractions::ClearUserInfractions;
+crate use get_error_report::GetErrorReport;
 crate use get_guild_configuration::GetGuildConfiguration;
 crate use get_guild_infractions::GetGuildInfractions;
 crate use get_local_user_infractions::GetLocalUserInfractions;
+crate use get_scheduled_actions::GetScheduledActions;
 crate use get_whitelisted_guilds::GetWhitelistedGuilds;
 crate use initialize_whitelisted_guild::InitializeWhitelistedGuild;
+crate use remove_scheduled_action::RemoveScheduledAction;
 crate use remove_user_infraction::RemoveUserInfraction;
+crate use set_guild_configuration::SetGuildConfiguration;
 crate use update_user_infraction::UpdateUserInfraction;
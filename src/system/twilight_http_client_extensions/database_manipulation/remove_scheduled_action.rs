@@ -0,0 +1,80 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll
+    }
+};
+
+use sqlx::{
+    postgres::PgPool
+};
+
+use crate::logging::logger::Logger;
+use crate::system::{
+    twilight_http_client_extensions::{
+        error::ClientExtensionResult,
+        Pending
+    }
+};
+
+crate struct RemoveScheduledAction {
+    future: Option<Pending<()>>,
+
+    connection: PgPool,
+    id: u64
+}
+
+impl RemoveScheduledAction {
+    crate fn new(connection: PgPool, id: u64) -> Self {
+        Self {
+            future: None,
+
+            connection,
+            id
+        }
+    }
+
+    fn start(&mut self) -> ClientExtensionResult<()> {
+        self.future.replace(Box::pin(request(self.connection.clone(), self.id)));
+
+        Ok(())
+    }
+}
+
+impl Future for RemoveScheduledAction {
+    type Output = ClientExtensionResult<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future.as_mut() {
+                return future.as_mut().poll(cx);
+            }
+
+            if let Err(error) = self.start() {
+                return Poll::Ready(Err(error));
+            }
+        }
+    }
+}
+
+unsafe impl Send for RemoveScheduledAction {}
+
+async fn request(connection: PgPool, id: u64) -> ClientExtensionResult<()> {
+    Logger::log_debug(format!("Making query to database. [Removing scheduled action: {}]", id));
+
+    if let Err(error) = sqlx::query(
+        // language=SQL
+        "DELETE FROM scheduled_actions WHERE id = $1; --"
+    )
+        .bind(id as i64)
+        .execute(&connection)
+        .await {
+        Logger::log_error(format!("Could not remove scheduled action. Error: {}", error));
+
+        return Err(box error);
+    }
+
+    Ok(())
+}
@@ -1,6 +1,5 @@
 use std::{
     future::Future,
-    env::*,
     pin::Pin,
     task::{
         Context,
@@ -9,14 +8,7 @@ use std::{
 };
 
 use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
-    postgres::{
-        PgPool,
-        PgRow
-    },
-    Row
+    postgres::PgPool
 };
 
 use twilight_model::{
@@ -26,41 +18,51 @@ use twilight_model::{
     },
 };
 
-use crate::command_system::CommandError;
-use crate::logging::logger::Logger;
+use crate::command_system::{
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    },
+    CommandError
+};
 use crate::system::{
+    model::{
+        infractions::Infraction,
+        payload::InfractionRemoved
+    },
     twilight_http_client_extensions::{
         error::ClientExtensionResult,
         Pending
-    },
-    twilight_id_extensions::IntoInnerU64
+    }
 };
 
 crate struct RemoveUserInfraction {
     future: Option<Pending<()>>,
 
+    connection: PgPool,
     guild_id: GuildId,
     user_id: UserId,
     infraction_id: String,
+    emitter: CommandEventEmitter
 }
 
 impl RemoveUserInfraction {
-    crate fn new(infraction_id: String, guild_id: GuildId, user_id: UserId) -> Self {
+    crate fn new(connection: PgPool, infraction_id: String, guild_id: GuildId, user_id: UserId,
+               emitter: CommandEventEmitter) -> Self {
         RemoveUserInfraction {
             future: None,
 
+            connection,
             guild_id,
             user_id,
-            infraction_id,
+            infraction_id: Infraction::lookup_id(&infraction_id),
+            emitter
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
-        Logger::log_debug(
-            "Attempting to create connection to HarTexBetaGuildInfractions database.".to_string());
-
-        self.future.replace(Box::pin(request(self.guild_id, self.user_id,
-                                             self.infraction_id.clone())));
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id, self.user_id,
+                                             self.infraction_id.clone(), self.emitter.clone())));
 
         Ok(())
     }
@@ -84,34 +86,31 @@ impl Future for RemoveUserInfraction {
 
 unsafe impl Send for RemoveUserInfraction {}
 
-async fn request(guild_id: GuildId, user_id: UserId, infraction_id: String) -> ClientExtensionResult<()> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILDS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
+async fn request(connection: PgPool, guild_id: GuildId, user_id: UserId, infraction_id: String,
+                 emitter: CommandEventEmitter) -> ClientExtensionResult<()> {
     let query_result = sqlx::query(
-        &format!(
-            // language=SQL
-            "DELETE FROM inf_{}.user_{} WHERE infraction_id = $1",
-            guild_id.into_inner_u64(),
-            user_id.into_inner_u64()
-        )
+        // language=SQL
+        "DELETE FROM infractions WHERE guild_id = $1 AND user_id = $2 AND infraction_id = $3; --"
     )
-        .bind::<String>(infraction_id)
-        .fetch_all(&connection)
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
+        .bind(infraction_id.clone())
+        .execute(&connection)
         .await;
 
-    if let Err(error) = query_result {
-        Err(box error)
-    }
-    else {
-        Ok(())
+    match query_result {
+        Ok(result) if result.rows_affected() == 0 => {
+            return Err(box CommandError::User(format!("No infraction #{} found for that user.", infraction_id)));
+        },
+        Err(error) => return Err(box error),
+        _ => ()
     }
+
+    emitter.event(SystemEvent::InfractionRemoved(box InfractionRemoved {
+        guild_id,
+        user_id,
+        infraction_id
+    })).await;
+
+    Ok(())
 }
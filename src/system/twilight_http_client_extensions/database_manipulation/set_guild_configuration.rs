@@ -0,0 +1,123 @@
+extern crate base64;
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{
+        Context,
+        Poll
+    }
+};
+
+use base64::encode;
+
+use sqlx::postgres::PgPool;
+
+use twilight_model::{
+    id::GuildId
+};
+
+use crate::command_system::{
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    }
+};
+use crate::logging::logger::Logger;
+use crate::system::{
+    model::payload::ConfigChanged,
+    twilight_http_client_extensions::{
+        error::ClientExtensionResult,
+        Pending
+    },
+    twilight_id_extensions::IntoInnerU64
+};
+
+/// Replaces the configuration of a guild, emitting `ConfigChanged` so that the cached configuration is read again.
+crate struct SetGuildConfiguration {
+    future: Option<Pending<()>>,
+
+    connection: PgPool,
+    guild_id: GuildId,
+    config: String,
+    emitter: CommandEventEmitter
+}
+
+impl SetGuildConfiguration {
+    crate fn new(connection: PgPool, guild_id: GuildId, config: String, emitter: CommandEventEmitter) -> Self {
+        Self {
+            future: None,
+
+            connection,
+            guild_id,
+            config,
+            emitter
+        }
+    }
+
+    fn start(&mut self) -> ClientExtensionResult<()> {
+        self.future.replace(Box::pin(request(self.connection.clone(), self.guild_id, self.config.clone(),
+                                             self.emitter.clone())));
+
+        Ok(())
+    }
+}
+
+impl Future for SetGuildConfiguration {
+    type Output = ClientExtensionResult<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        loop {
+            if let Some(future) = self.as_mut().future.as_mut() {
+                return future.as_mut().poll(cx);
+            }
+
+            if let Err(error) = self.start() {
+                return Poll::Ready(Err(error));
+            }
+        }
+    }
+}
+
+unsafe impl Send for SetGuildConfiguration {}
+
+async fn request(connection: PgPool, guild_id: GuildId, config: String, emitter: CommandEventEmitter)
+    -> ClientExtensionResult<()> {
+    Logger::log_debug(format!("Making query to database. [Setting guild configuration for guild: {}]", guild_id));
+
+    let schema = format!("guild_{}", guild_id.into_inner_u64());
+
+    // Each guild keeps its configuration in a schema of its own, which the dashboard also writes to, so the schema
+    // is created with the first configuration of the guild rather than by a migration.
+    let mut transaction = connection.begin().await?;
+
+    sqlx::query(&format!("CREATE SCHEMA IF NOT EXISTS {}; --", schema))
+        .execute(&mut transaction)
+        .await?;
+    sqlx::query(&format!("CREATE TABLE IF NOT EXISTS {}.plain_config (config TEXT NOT NULL); --", schema))
+        .execute(&mut transaction)
+        .await?;
+    sqlx::query(&format!("DELETE FROM {}.plain_config; --", schema))
+        .execute(&mut transaction)
+        .await?;
+
+    if let Err(error) = sqlx::query(
+        // language=SQL
+        &format!("INSERT INTO {}.plain_config (config) VALUES ($1); --", schema)
+    )
+        .bind(encode(config))
+        .execute(&mut transaction)
+        .await {
+        Logger::log_error(format!("Could not set guild configuration. Error: {}", error));
+
+        return Err(box error);
+    }
+
+    transaction.commit().await?;
+
+    emitter.event(SystemEvent::ConfigChanged(box ConfigChanged {
+        guild_id
+    })).await;
+
+    Ok(())
+}
@@ -1,6 +1,5 @@
 use std::{
     future::Future,
-    env::*,
     pin::Pin,
     task::{
         Context,
@@ -8,15 +7,10 @@ use std::{
     }
 };
 
+use chrono::Utc;
+
 use sqlx::{
-    error::{
-        Result as SqlxResult
-    },
-    postgres::{
-        PgPool,
-        PgRow
-    },
-    Row
+    postgres::PgPool
 };
 
 use twilight_model::{
@@ -26,48 +20,58 @@ use twilight_model::{
     },
 };
 
-use crate::command_system::CommandError;
-use crate::logging::logger::Logger;
+use crate::command_system::{
+    events::{
+        emitter::CommandEventEmitter,
+        events::SystemEvent
+    },
+    CommandError
+};
 use crate::system::{
     model::{
-        infractions::{
-            Infraction,
-            InfractionType
-        },
-        infraction_update_type::InfractionUpdateType
+        infractions::Infraction,
+        infraction_update_type::InfractionUpdateType,
+        payload::InfractionUpdated
     },
     twilight_http_client_extensions::{
         error::ClientExtensionResult,
         Pending
-    },
-    twilight_id_extensions::IntoInnerU64
+    }
 };
 
+use super::get_local_user_infractions::infraction_from_row;
+
 crate struct UpdateUserInfraction {
     future: Option<Pending<Infraction>>,
 
+    connection: PgPool,
     infraction_id: String,
     guild_id: GuildId,
     user_id: UserId,
-    update_type: InfractionUpdateType
+    update_type: InfractionUpdateType,
+    emitter: CommandEventEmitter
 }
 
 impl UpdateUserInfraction {
-    crate fn new(infraction_id: String, guild_id: GuildId, user_id: UserId, update_type: InfractionUpdateType) -> Self {
+    crate fn new(connection: PgPool, infraction_id: String, guild_id: GuildId, user_id: UserId,
+               update_type: InfractionUpdateType, emitter: CommandEventEmitter) -> Self {
         Self {
             future: None,
 
-            infraction_id,
+            connection,
+            infraction_id: Infraction::lookup_id(&infraction_id),
             guild_id,
             user_id,
-            update_type
+            update_type,
+            emitter
         }
     }
 
     fn start(&mut self) -> ClientExtensionResult<()> {
         self.future.replace(
             Box::pin(request(
-                self.infraction_id.clone(), self.update_type.clone(), self.user_id, self.guild_id)
+                self.connection.clone(), self.infraction_id.clone(), self.update_type.clone(), self.user_id, self.guild_id,
+                self.emitter.clone())
             ));
 
         Ok(())
@@ -92,64 +96,62 @@ impl Future for UpdateUserInfraction {
 
 unsafe impl Send for UpdateUserInfraction {}
 
-async fn request(infraction_id: String, update_type: InfractionUpdateType, user_id: UserId, guild_id: GuildId) -> ClientExtensionResult<Infraction> {
-    let database_credentials = if let Ok(credentials) = var("PGSQL_CREDENTIALS_GUILD_INFRACTIONS") {
-        credentials
-    }
-    else {
-        return Err(box CommandError("Credentials is none.".to_string()))
-    };
-
-    let connection = PgPool::connect(
-        &database_credentials
-    ).await?;
-
+async fn request(connection: PgPool, infraction_id: String, update_type: InfractionUpdateType, user_id: UserId,
+                 guild_id: GuildId, emitter: CommandEventEmitter) -> ClientExtensionResult<Infraction> {
     let infraction_old = match sqlx::query(
-        &format!(
-            // language=SQL
-            "SELECT * FROM {} WHERE infraction_id = $1",
-            format!("inf_{}.user_{}", guild_id.into_inner_u64(), user_id.into_inner_u64())
-        )
+        // language=SQL
+        "SELECT * FROM infractions WHERE guild_id = $1 AND user_id = $2 AND infraction_id = $3; --"
     )
-        .bind(infraction_id)
-        .fetch_one(&connection)
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
+        .bind(infraction_id.clone())
+        .fetch_optional(&connection)
         .await {
-        Ok(row) => {
-            let inf_id: String = row.get("infraction_id");
-            let reason: String = row.get("reason");
-            let inf_type = match row.get::<&str, &str>("infraction_type") {
-                "warning" => InfractionType::Warning,
-                "mute" => InfractionType::Mute,
-                "unmute" => InfractionType::Unmute,
-                "temp-mute" => InfractionType::TemporaryMute,
-                "kick" => InfractionType::Kick,
-                "ban" => InfractionType::Ban,
-                "unban" => InfractionType::Unban,
-                _ => unreachable!()
-            };
-
-            Infraction::new(inf_id, reason, inf_type)
+        Ok(Some(row)) => infraction_from_row(&row)?,
+        Ok(None) => {
+            return Err(box CommandError::User(format!("No infraction #{} found for that user.", infraction_id)))
         },
         Err(error) => {
             return Err(box error)
         }
     };
 
-    match update_type {
+    match update_type.clone() {
         InfractionUpdateType::Reason { new_reason } => {
             sqlx::query(
-                &format!(
-                    // language=SQL
-                    "UPDATE {} SET reason = $1 WHERE infraction_id = $2",
-                    format!("inf_{}.user_{}", guild_id.into_inner_u64(), user_id.into_inner_u64())
-                )
+                // language=SQL
+                "UPDATE infractions SET reason = $1 WHERE guild_id = $2 AND user_id = $3 AND infraction_id = $4; --"
             )
                 .bind(new_reason)
+                .bind(guild_id.0 as i64)
+                .bind(user_id.0 as i64)
                 .bind(infraction_old.clone().infraction_id)
-                .fetch_one(&connection)
+                .execute(&connection)
+                .await?;
+        },
+        InfractionUpdateType::Revoke { revoked_by, reason } => {
+            sqlx::query(
+                // language=SQL
+                "UPDATE infractions SET active = FALSE, revoked_by = $1, revoked_at = $2, revocation_reason = $3 \
+                 WHERE guild_id = $4 AND user_id = $5 AND infraction_id = $6; --"
+            )
+                .bind(revoked_by.0 as i64)
+                .bind(Utc::now())
+                .bind(reason)
+                .bind(guild_id.0 as i64)
+                .bind(user_id.0 as i64)
+                .bind(infraction_old.clone().infraction_id)
+                .execute(&connection)
                 .await?;
         }
     };
 
+    emitter.event(SystemEvent::InfractionUpdated(box InfractionUpdated {
+        guild_id,
+        user_id,
+        infraction_id: infraction_old.infraction_id.clone(),
+        update_type
+    })).await;
+
     Ok(infraction_old)
 }
@@ -8,6 +8,10 @@ crate const fn content_distribution_network_base_url() -> &'static str {
     CONTENT_DISTRIBUTION_NETWORK_BASE_URL
 }
 
+crate const fn default_command_prefix() -> &'static str {
+    "hb."
+}
+
 crate const fn hartex_guild_owner() -> RoleId {
     RoleId(791588740270784512)
 }
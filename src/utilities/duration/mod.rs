@@ -2,35 +2,47 @@ use std::{
     time::Duration
 };
 
-crate fn parse_duration(duration: String) -> Duration {
+/// Parses a duration such as `1d12h`, `30m` or `45s`; a trailing number without a unit is taken as seconds.
+crate fn parse_duration(duration: &str) -> Option<Duration> {
     let mut acc = 0u64;
     let mut dur = 0u64;
+    let mut digits = false;
+
+    if duration.is_empty() {
+        return None;
+    }
 
     for c in duration.chars() {
         match c {
-            | '0'..'9' => {
-                acc *= 10;
-                acc += c.to_digit(10).unwrap() as u64;
+            | '0'..='9' => {
+                acc = acc.checked_mul(10)?.checked_add(c.to_digit(10)? as u64)?;
+                digits = true;
+                continue;
             },
             | 'd' | 'D' => {
-                dur += acc * 24 * 60 * 60;
-                acc = 0;
-            },
-            | 'm' | 'M' => {
-                dur += acc * 60;
-                acc = 0;
+                dur = dur.checked_add(acc.checked_mul(24 * 60 * 60)?)?;
             },
             | 'h' | 'H' => {
-                dur += acc * 60 * 60;
-                acc = 0;
+                dur = dur.checked_add(acc.checked_mul(60 * 60)?)?;
+            },
+            | 'm' | 'M' => {
+                dur = dur.checked_add(acc.checked_mul(60)?)?;
             },
             | 's' | 'S' => {
-                dur += acc * 60;
-                acc = 0;
+                dur = dur.checked_add(acc)?;
             },
-            _ => unreachable!(),
+            _ => return None,
+        }
+
+        if !digits {
+            return None;
         }
+
+        acc = 0;
+        digits = false;
     }
 
-    Duration::from_secs(dur)
+    dur = dur.checked_add(acc)?;
+
+    Some(Duration::from_secs(dur))
 }
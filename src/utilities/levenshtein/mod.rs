@@ -0,0 +1,20 @@
+/// Computes the Levenshtein edit distance between two strings, compared by characters.
+crate fn levenshtein_distance(left: &str, right: &str) -> usize {
+    let right = right.chars().collect::<Vec<_>>();
+    let mut previous = (0..=right.len()).collect::<Vec<_>>();
+    let mut current = vec![0; right.len() + 1];
+
+    for (i, left_char) in left.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + if left_char == *right_char { 0 } else { 1 };
+
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[right.len()]
+}
@@ -1,5 +1,6 @@
 crate mod constants;
 crate mod duration;
+crate mod levenshtein;
 
 use std::{
     error::Error,
@@ -1,20 +1,50 @@
 extern crate serde;
 extern crate quick_xml;
 
-#[derive(Debug, Serialize, Deserialize)]
+use serde::{
+    Deserialize,
+    Deserializer
+};
+
+use crate::utilities::constants::default_command_prefix;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 crate struct BotCustomization {
-    #[serde(rename = "CommandPrefix")]
+    #[serde(rename = "CommandPrefix", deserialize_with = "deserialize_command_prefix")]
     crate command_prefix: String,
 
     #[serde(rename = "GuildNickname")]
     crate guild_nickname: String,
+
+    #[serde(rename = "SuggestCommands", default = "default_suggest_commands")]
+    crate suggest_commands: bool
+}
+
+/// Reads the command prefix, using the default prefix instead of a blank one, which would make every message a
+/// command.
+fn deserialize_command_prefix<'deserialize, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'deserialize> {
+    let prefix = String::deserialize(deserializer)?;
+
+    if prefix.trim().is_empty() {
+        Ok(String::from(default_command_prefix()))
+    }
+    else {
+        Ok(prefix)
+    }
+}
+
+fn default_suggest_commands() -> bool {
+    true
 }
 
 impl Default for BotCustomization {
     fn default() -> Self {
         Self {
-            command_prefix: String::from("hb."),
-            guild_nickname: String::from("HarTex")
+            command_prefix: String::from(default_command_prefix()),
+            guild_nickname: String::from("HarTex"),
+            suggest_commands: default_suggest_commands()
         }
     }
 }
@@ -0,0 +1,9 @@
+extern crate serde;
+extern crate quick_xml;
+
+/// The destructive commands that ask the invoker to confirm with a reaction before they run.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+crate struct CommandConfirmations {
+    #[serde(rename = "Command", default)]
+    crate commands: Vec<String>
+}
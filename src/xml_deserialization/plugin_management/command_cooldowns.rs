@@ -0,0 +1,25 @@
+extern crate serde;
+extern crate quick_xml;
+
+use crate::command_system::precommand_checks::CooldownBucket;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+crate struct CommandCooldowns {
+    #[serde(rename = "CommandCooldown", default)]
+    crate cooldowns: Vec<CommandCooldown>
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+crate struct CommandCooldown {
+    #[serde(rename = "Command")]
+    crate command: String,
+
+    #[serde(rename = "Bucket")]
+    crate bucket: CooldownBucket,
+
+    #[serde(rename = "WindowSeconds")]
+    crate window_seconds: u64,
+
+    #[serde(rename = "Burst")]
+    crate burst: u32
+}
@@ -0,0 +1,46 @@
+extern crate serde;
+extern crate quick_xml;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+crate struct CommandRestrictions {
+    #[serde(rename = "CommandRestriction", default)]
+    crate restrictions: Vec<CommandRestriction>
+}
+
+/// Restricts a command, or every command of a group such as `inf`, in a guild.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+crate struct CommandRestriction {
+    #[serde(rename = "Command")]
+    crate command: String,
+
+    #[serde(rename = "Enabled", default = "default_enabled")]
+    crate enabled: bool,
+
+    #[serde(rename = "AllowedChannels", default)]
+    crate allowed_channels: Channels,
+
+    #[serde(rename = "DeniedChannels", default)]
+    crate denied_channels: Channels,
+
+    #[serde(rename = "AllowedRoles", default)]
+    crate allowed_roles: Roles,
+
+    #[serde(rename = "DeniedRoles", default)]
+    crate denied_roles: Roles
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+crate struct Channels {
+    #[serde(rename = "ChannelId", default)]
+    crate channel_ids: Vec<u64>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+crate struct Roles {
+    #[serde(rename = "RoleId", default)]
+    crate role_ids: Vec<u64>
+}
+
+fn default_enabled() -> bool {
+    true
+}
@@ -1,9 +1,21 @@
 crate mod command;
+mod command_confirmations;
+mod command_cooldowns;
+mod command_restrictions;
 mod plugins;
 
 // Plugins
 mod infractions_plugin;
 
+crate use command_confirmations::CommandConfirmations;
+crate use command_cooldowns::{
+    CommandCooldown,
+    CommandCooldowns
+};
+crate use command_restrictions::{
+    CommandRestriction,
+    CommandRestrictions
+};
 crate use plugins::Plugins;
 
 crate use infractions_plugin::InfractionsPlugin;
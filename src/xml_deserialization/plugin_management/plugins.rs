@@ -1,10 +1,24 @@
 extern crate serde;
 extern crate quick_xml;
 
-use super::InfractionsPlugin;
+use super::{
+    CommandConfirmations,
+    CommandCooldowns,
+    CommandRestrictions,
+    InfractionsPlugin
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 crate struct Plugins {
     #[serde(rename = "InfractionsPlugin")]
-    crate infractions_plugin: InfractionsPlugin
+    crate infractions_plugin: InfractionsPlugin,
+
+    #[serde(rename = "CommandCooldowns", default)]
+    crate command_cooldowns: CommandCooldowns,
+
+    #[serde(rename = "CommandRestrictions", default)]
+    crate command_restrictions: CommandRestrictions,
+
+    #[serde(rename = "CommandConfirmations", default)]
+    crate command_confirmations: CommandConfirmations
 }